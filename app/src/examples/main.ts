@@ -100,6 +100,7 @@ export function createTestTransferInstruction(
         finalBufferHash: Array.from(messageHash),
         finalBufferSize: messageBuffer.length,
         buffer: firstSlice,
+        chunkMerkleRoot: null,
       } as superTxn.generated.TransactionBufferCreateArgs,
     } as superTxn.generated.TxnBufferCreateInstructionArgs
   );
@@ -179,6 +180,14 @@ export function createTestTransferInstruction(
           ephemeralSigners: 0,
           transactionMessage: new Uint8Array(6).fill(0),
           memo: null,
+          approval: null,
+          executor: { __kind: "Creator" },
+          bountyLamports: 0,
+          validAfter: null,
+          validUntil: null,
+          assertions: [],
+          captureReturnData: false,
+          strict: false,
         } as superTxn.generated.SuperTransactionCreateArgs,
       } as superTxn.generated.SuperTransactionCreateFromBufferInstructionArgs
     );
@@ -397,6 +397,7 @@ async function createTokenConversionRouteSpur(
               finalBufferHash: Array.from(messageHash),
               finalBufferSize: messageBuffer.length,
               buffer: firstSlice,
+              chunkMerkleRoot: null,
             } as superTxn.generated.TransactionBufferCreateArgs,
           } as superTxn.generated.TxnBufferCreateInstructionArgs
         );
@@ -435,6 +436,8 @@ async function createTokenConversionRouteSpur(
               {
                 args: {
                   buffer: chunk,
+                  chunkProof: null,
+                  strict: false,
                 } as superTxn.generated.TransactionBufferExtendArgs,
               } as superTxn.generated.TxnBufferExtendInstructionArgs,
               programId
@@ -473,6 +476,14 @@ async function createTokenConversionRouteSpur(
                 ephemeralSigners: 0,
                 transactionMessage: new Uint8Array(6).fill(0),
                 memo: null,
+                approval: null,
+                executor: { __kind: "Creator" },
+                bountyLamports: 0,
+                validAfter: null,
+                validUntil: null,
+                assertions: [],
+                captureReturnData: false,
+                strict: false,
               } as superTxn.generated.SuperTransactionCreateArgs,
             } as superTxn.generated.SuperTransactionCreateFromBufferInstructionArgs
           );
@@ -49,6 +49,29 @@ export type SuperTxn = {
       "docs": [
         "Close a transaction buffer account."
       ],
+      "accounts": [
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The member of the multisig that created the TransactionBuffer.",
+            "Rent gets returned to the creator."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "txnBufferRealloc",
+      "docs": [
+        "Grow a transaction buffer account by up to 10240 bytes towards its final size."
+      ],
       "accounts": [
         {
           "name": "transactionBuffer",
@@ -62,6 +85,19 @@ export type SuperTxn = {
           "docs": [
             "The member of the multisig that created the TransactionBuffer."
           ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the additional rent."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -80,9 +116,71 @@ export type SuperTxn = {
         {
           "name": "creator",
           "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransactionBufferExtendArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "txnBufferWriteAt",
+      "docs": [
+        "Write a slice of a transaction buffer account at the given offset.",
+        "Slices can land in any order."
+      ],
+      "accounts": [
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransactionBufferWriteAtArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "txnBufferExecute",
+      "docs": [
+        "Execute the message of a finalized transaction buffer and close the buffer,",
+        "without creating a super transaction."
+      ],
+      "accounts": [
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
           "isSigner": true,
           "docs": [
-            "The member of the multisig that created the TransactionBuffer."
+            "The creator of the buffer, receives the rent refund."
+          ]
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
           ]
         }
       ],
@@ -90,7 +188,7 @@ export type SuperTxn = {
         {
           "name": "args",
           "type": {
-            "defined": "TransactionBufferExtendArgs"
+            "defined": "TransactionBufferExecuteArgs"
           }
         }
       ]
@@ -195,11 +293,59 @@ export type SuperTxn = {
         }
       ]
     },
+    {
+      "name": "superTransactionRealloc",
+      "docs": [
+        "Grow a super transaction created from a buffer too large to be written in one instruction.",
+        "Once the account is large enough the message is written and the buffer is closed."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The creator of the transaction and the transaction buffer."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the additional rent."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "superTransactionExecute",
       "docs": [
         "Execute a super transaction.",
-        "The transaction must be `Approved`."
+        "Transactions capturing return data are kept open until the creator closes them,",
+        "the last captured return data is set as the return data of this instruction.",
+        "If the transaction has an approval policy it must be `Approved` and its time lock released.",
+        "The executor must be allowed by the transaction `executor`, the rent is refunded to the creator.",
+        "The escrowed bounty is paid to the executor once every instruction has been executed.",
+        "The current slot and timestamp must be within the transaction validity window.",
+        "The transaction assertions are checked after the last instruction.",
+        "Every called program must be allowed by the creator execution policy."
       ],
       "accounts": [
         {
@@ -213,15 +359,49 @@ export type SuperTxn = {
         {
           "name": "creator",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false,
+          "docs": [
+            "The creator of the transaction, receives the rent refund."
+          ]
+        },
+        {
+          "name": "executor",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The signer executing the transaction, must be allowed by `transaction.executor`.",
+            "Receives the bounty once the transaction is fully executed,",
+            "pays the rent of the captured return data."
+          ]
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SuperTransactionExecuteArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "superTransactionAccountsClose",
+      "name": "superTransactionExecuteRange",
       "docs": [
-        "Closes a `SuperTransaction`"
+        "Execute up to `max_instructions` instructions of a super transaction and save the progress.",
+        "The transaction is closed once its last instruction has been executed."
       ],
       "accounts": [
         {
@@ -229,13 +409,34 @@ export type SuperTxn = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "SuperTransaction corresponding to the `proposal`."
+            "The transaction to execute."
           ]
         },
         {
           "name": "creator",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false,
+          "docs": [
+            "The creator of the transaction, receives the rent refund."
+          ]
+        },
+        {
+          "name": "executor",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The signer executing the transaction, must be allowed by `transaction.executor`.",
+            "Receives the bounty once the transaction is fully executed,",
+            "pays the rent of the captured return data."
+          ]
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
+          ]
         },
         {
           "name": "systemProgram",
@@ -243,244 +444,3265 @@ export type SuperTxn = {
           "isSigner": false
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "transactionBuffer",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "docs": [
-              "txn buffer creator"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "bufferIndex",
-            "docs": [
-              "Index to seed address derivation"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "finalBufferHash",
-            "docs": [
-              "Hash of the final assembled transaction message."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "finalBufferSize",
-            "docs": [
-              "The size of the final assembled transaction message."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "buffer",
-            "docs": [
-              "The buffer of the transaction message."
-            ],
-            "type": "bytes"
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SuperTransactionExecuteRangeArgs"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "superTransaction",
+      "name": "superTransactionExecuteBestEffort",
       "docs": [
-        "Stores data required for tracking the voting and execution status of a super transaction.",
-        "Super transaction is a transaction wraps arbitrary Solana instructions, typically calling into other Solana programs."
+        "Execute the remaining instructions of a super transaction, skipping the ones that",
+        "reference missing accounts or a non-executable program instead of failing.",
+        "The skipped instructions are reported in the `InstructionsSkipped` event."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "docs": [
-              "SuperTransaction creator"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "ephemeralSignerBumps",
-            "docs": [
-              "Index of this transaction within the bundle. (0 if it is not)",
-              "Derivation bumps for additional signers.",
-              "Some transactions require multiple signers. Often these additional signers are \"ephemeral\" keypairs",
-              "that are generated on the client with a sole purpose of signing the transaction and be discarded immediately after.",
-              "When wrapping such transactions into multisig ones, we replace these \"ephemeral\" signing keypairs",
-              "with PDAs derived from the MultisigTransaction's `transaction_index` and controlled by the Multisig Program;",
-              "during execution the program includes the seeds of these PDAs into the `invoke_signed` calls,",
-              "thus \"signing\" on behalf of these PDAs."
-            ],
-            "type": "bytes"
-          },
-          {
-            "name": "message",
-            "docs": [
-              "data required for executing the transaction."
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The transaction to execute."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The creator of the transaction, receives the rent refund."
+          ]
+        },
+        {
+          "name": "executor",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The signer executing the transaction, must be allowed by `transaction.executor`.",
+            "Receives the bounty once the transaction is fully executed,",
+            "pays the rent of the captured return data."
+          ]
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SuperTransactionExecuteBestEffortArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "superTransactionApprove",
+      "docs": [
+        "Approve a super transaction on behalf of an approval policy member."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The approval policy member casting the vote."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "superTransactionReject",
+      "docs": [
+        "Reject a super transaction on behalf of an approval policy member."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The approval policy member casting the vote."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "superTransactionCancel",
+      "docs": [
+        "Cancel an approved super transaction on behalf of an approval policy member."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The approval policy member casting the vote."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executionPolicyCreate",
+      "docs": [
+        "Create the execution policy restricting the programs the creator transactions can call."
+      ],
+      "accounts": [
+        {
+          "name": "executionPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the execution policy account rent."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExecutionPolicyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executionPolicyUpdate",
+      "docs": [
+        "Replace the program lists of an execution policy."
+      ],
+      "accounts": [
+        {
+          "name": "executionPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the additional rent, refunded if the policy shrinks."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExecutionPolicyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executionPolicyClose",
+      "docs": [
+        "Close an execution policy, transactions can then call any program but super_txn."
+      ],
+      "accounts": [
+        {
+          "name": "executionPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "batchCreate",
+      "docs": [
+        "Create a batch of super transactions that are executed in order."
+      ],
+      "accounts": [
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The creator of the batch and its transactions."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the rent of the batch and all of its transactions."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BatchCreateArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "batchAddTransaction",
+      "docs": [
+        "Add a super transaction to the end of a batch."
+      ],
+      "accounts": [
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The creator of the batch."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the transaction account rent, must be the batch rent payer."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BatchAddTransactionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "batchExecuteNext",
+      "docs": [
+        "Execute the next super transaction of a batch.",
+        "The batch is closed once all of its transactions have been executed."
+      ],
+      "accounts": [
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The next transaction of the batch to execute."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The batch rent payer, receives the rent of the executed transaction",
+            "and of the batch once it is complete."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "batchClose",
+      "docs": [
+        "Close a batch and its transactions that haven't been executed, in order.",
+        "The rent is refunded to the batch rent payer."
+      ],
+      "accounts": [
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The batch rent payer, receives the rent of the batch and its transactions."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "superTransactionAccountsClose",
+      "docs": [
+        "Closes a `SuperTransaction`"
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "superTransactionCloseExpired",
+      "docs": [
+        "Closes an expired `SuperTransaction`, can be called by anyone.",
+        "The rent is refunded to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The creator of the transaction, receives the rent refund."
+          ]
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "batch",
+      "docs": [
+        "Groups several `SuperTransaction`s of one creator that are executed in order.",
+        "Batch transactions are derived from the batch key and their index in the batch."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "Batch creator"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "Index to seed address derivation"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "Payer of the rent of the batch and its transactions, refunded when they are closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "size",
+            "docs": [
+              "Number of transactions added to the batch."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "nextTransactionIndex",
+            "docs": [
+              "Index of the next transaction to execute."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "executionPolicy",
+      "docs": [
+        "Per-creator restriction of the programs the instructions of its transactions can call.",
+        "Without a policy every program but super_txn itself can be called."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "Creator the policy applies to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "allowedPrograms",
+            "docs": [
+              "If not empty, only these programs can be called."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "deniedPrograms",
+            "docs": [
+              "Programs that can never be called."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "transactionBuffer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "txn buffer creator"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bufferIndex",
+            "docs": [
+              "Index to seed address derivation"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "finalBufferHash",
+            "docs": [
+              "Hash of the final assembled transaction message."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "finalBufferSize",
+            "docs": [
+              "The size of the final assembled transaction message."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "chunkMerkleRoot",
+            "docs": [
+              "Optional Merkle root over the `TRANSACTION_BUFFER_CHUNK_SIZE` chunks of the final buffer.",
+              "When set, every chunk must come with a proof and is verified as it is written."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "buffer",
+            "docs": [
+              "The buffer of the transaction message."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "writtenChunks",
+            "docs": [
+              "Bitmap of the `TRANSACTION_BUFFER_CHUNK_SIZE` chunks of `buffer` that have been written."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "superTransaction",
+      "docs": [
+        "Stores data required for tracking the voting and execution status of a super transaction.",
+        "Super transaction is a transaction wraps arbitrary Solana instructions, typically calling into other Solana programs."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "SuperTransaction creator"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "Index of the transaction, used to seed the account derivation."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAtSlot",
+            "docs": [
+              "Slot at which the transaction was created."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAtUnix",
+            "docs": [
+              "Unix timestamp at which the transaction was created."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "memo",
+            "docs": [
+              "Optional memo describing the purpose of the transaction, up to `MAX_MEMO_LEN` bytes."
+            ],
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "approval",
+            "docs": [
+              "Optional approval policy, the transaction can only be executed once it is `Approved`."
+            ],
+            "type": {
+              "option": {
+                "defined": "ApprovalPolicy"
+              }
+            }
+          },
+          {
+            "name": "executor",
+            "docs": [
+              "Who is allowed to execute the transaction."
+            ],
+            "type": {
+              "defined": "Executor"
+            }
+          },
+          {
+            "name": "bountyLamports",
+            "docs": [
+              "Lamports escrowed on the account on top of its rent, paid to the executor",
+              "once every instruction has been executed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "validAfter",
+            "docs": [
+              "The transaction can't be executed before this slot or timestamp."
+            ],
+            "type": {
+              "option": {
+                "defined": "ValidityBound"
+              }
+            }
+          },
+          {
+            "name": "validUntil",
+            "docs": [
+              "The transaction can't be executed after this slot or timestamp."
+            ],
+            "type": {
+              "option": {
+                "defined": "ValidityBound"
+              }
+            }
+          },
+          {
+            "name": "assertions",
+            "docs": [
+              "Checked once every instruction has been executed, the execution reverts if any fails."
+            ],
+            "type": {
+              "vec": {
+                "defined": "Assertion"
+              }
+            }
+          },
+          {
+            "name": "ephemeralSignerBumps",
+            "docs": [
+              "Derivation bumps for additional signers.",
+              "Some transactions require multiple signers. Often these additional signers are \"ephemeral\" keypairs",
+              "that are generated on the client with a sole purpose of signing the transaction and be discarded immediately after.",
+              "When wrapping such transactions into multisig ones, we replace these \"ephemeral\" signing keypairs",
+              "with PDAs derived from the MultisigTransaction's `transaction_index` and controlled by the Multisig Program;",
+              "during execution the program includes the seeds of these PDAs into the `invoke_signed` calls,",
+              "thus \"signing\" on behalf of these PDAs."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "nextInstructionIndex",
+            "docs": [
+              "Index of the next instruction of `message` to execute.",
+              "Only advances when the transaction is executed in several ranges."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "skippedInstructions",
+            "docs": [
+              "Bitmap of the instructions skipped by a best-effort execution, one bit per instruction."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "captureReturnData",
+            "docs": [
+              "Capture the return data of the executed instructions into `return_data`.",
+              "The account is then kept open after execution until the creator closes it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "returnData",
+            "docs": [
+              "Return data captured from the executed instructions, in execution order."
+            ],
+            "type": {
+              "vec": {
+                "defined": "InstructionReturnData"
+              }
+            }
+          },
+          {
+            "name": "computeUnits",
+            "docs": [
+              "Compute units consumed by the executed instructions, in execution order.",
+              "Only recorded along with `return_data` when `capture_return_data` is set."
+            ],
+            "type": {
+              "vec": {
+                "defined": "InstructionComputeUnits"
+              }
+            }
+          },
+          {
+            "name": "pendingMessageHash",
+            "docs": [
+              "`final_buffer_hash` of the buffer holding the message while the transaction is pending,",
+              "only that buffer can complete it with `super_transaction_realloc`."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "batch",
+            "docs": [
+              "The batch the transaction was added to, it can then only be executed and closed",
+              "through the batch, in order."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "message",
+            "docs": [
+              "data required for executing the transaction."
+            ],
+            "type": {
+              "defined": "SuperTransactionMessage"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "ApprovalPolicy",
+      "docs": [
+        "Optional set of members that have to approve a `SuperTransaction` before it can be executed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "docs": [
+              "Members allowed to vote on the transaction."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Number of approvals required for the transaction to be `Approved`."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "timeLock",
+            "docs": [
+              "Number of seconds that must pass between approval and execution."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Current status of the transaction."
+            ],
+            "type": {
+              "defined": "ApprovalStatus"
+            }
+          },
+          {
+            "name": "approved",
+            "docs": [
+              "Members that approved the transaction."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "rejected",
+            "docs": [
+              "Members that rejected the transaction."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Members that cancelled the approved transaction."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InstructionReturnData",
+      "docs": [
+        "Return data set by a wrapped instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "docs": [
+              "Index of the instruction in the message."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "programId",
+            "docs": [
+              "Program that set the return data."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InstructionComputeUnits",
+      "docs": [
+        "Compute units consumed by a wrapped instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "docs": [
+              "Index of the instruction in the message."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "units",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperTransactionMessage",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "numSigners",
+            "docs": [
+              "The number of signer pubkeys in the account_keys vec."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "numWritableSigners",
+            "docs": [
+              "The number of writable signer pubkeys in the account_keys vec."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "numWritableNonSigners",
+            "docs": [
+              "The number of writable non-signer pubkeys in the account_keys vec."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "accountKeys",
+            "docs": [
+              "Unique account pubkeys (including program IDs) required for execution of the tx.",
+              "The signer pubkeys appear at the beginning of the vec, with writable pubkeys first, and read-only pubkeys following.",
+              "The non-signer pubkeys follow with writable pubkeys first and read-only ones following.",
+              "Program IDs are also stored at the end of the vec along with other non-signer non-writable pubkeys:",
+              "",
+              "```plaintext",
+              "[pubkey1, pubkey2, pubkey3, pubkey4, pubkey5, pubkey6, pubkey7, pubkey8]",
+              "|---writable---|  |---readonly---|  |---writable---|  |---readonly---|",
+              "|------------signers-------------|  |----------non-signers-----------|",
+              "```"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "instructions",
+            "docs": [
+              "List of instructions making up the tx."
+            ],
+            "type": {
+              "vec": {
+                "defined": "SuperCompiledInstruction"
+              }
+            }
+          },
+          {
+            "name": "addressTableLookups",
+            "docs": [
+              "List of address table lookups used to load additional accounts",
+              "for this transaction."
+            ],
+            "type": {
+              "vec": {
+                "defined": "SuperMessageAddressTableLookup"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperCompiledInstruction",
+      "docs": [
+        "Concise serialization schema for instructions that make up a transaction.",
+        "Closely mimics the Solana transaction wire format."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programIdIndex",
+            "type": "u8"
+          },
+          {
+            "name": "accountIndexes",
+            "docs": [
+              "Indices into the tx's `account_keys` list indicating which accounts to pass to the instruction."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Instruction data."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperMessageAddressTableLookup",
+      "docs": [
+        "Address table lookups describe an on-chain address lookup table to use",
+        "for loading more readonly and writable accounts into a transaction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "accountKey",
+            "docs": [
+              "Address lookup table account key."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "writableIndexes",
+            "docs": [
+              "List of indexes used to load writable accounts."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "readonlyIndexes",
+            "docs": [
+              "List of indexes used to load readonly accounts."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransactionBufferCreateArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bufferIndex",
+            "docs": [
+              "Index of the buffer account to seed the account derivation"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "finalBufferHash",
+            "docs": [
+              "Hash of the final assembled transaction message."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "finalBufferSize",
+            "docs": [
+              "Final size of the buffer."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "buffer",
+            "docs": [
+              "Initial slice of the buffer."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "chunkMerkleRoot",
+            "docs": [
+              "Optional Merkle root over the chunks of the final buffer.",
+              "When set, the initial slice must be empty and every chunk is uploaded with a proof."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransactionBufferExtendArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buffer",
+            "type": "bytes"
+          },
+          {
+            "name": "chunkProof",
+            "type": {
+              "option": {
+                "vec": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            }
+          },
+          {
+            "name": "strict",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransactionBufferWriteAtArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offset",
+            "docs": [
+              "Offset into the final buffer, must be a multiple of `TRANSACTION_BUFFER_CHUNK_SIZE`."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bytes",
+            "docs": [
+              "Bytes to write at `offset`."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "chunkProof",
+            "docs": [
+              "Merkle proof of the written chunk, required if the buffer has a `chunk_merkle_root`."
+            ],
+            "type": {
+              "option": {
+                "vec": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            }
+          },
+          {
+            "name": "strict",
+            "docs": [
+              "Fail with `MissingAccount` instead of silently succeeding if the buffer doesn't exist."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransactionBufferExecuteArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ephemeralSigners",
+            "docs": [
+              "Number of ephemeral signing PDAs required by the message, derived from the buffer key."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "strict",
+            "docs": [
+              "Fail with `MissingAccount` instead of silently succeeding if the buffer doesn't exist."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperTransactionCreateArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transactionIndex",
+            "docs": [
+              "Index of this transaction belongs to."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "ephemeralSigners",
+            "docs": [
+              "Number of ephemeral signing PDAs required by the transaction."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "transactionMessage",
+            "type": "bytes"
+          },
+          {
+            "name": "memo",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "approval",
+            "docs": [
+              "Optional approval policy the transaction has to satisfy before it can be executed."
+            ],
+            "type": {
+              "option": {
+                "defined": "ApprovalPolicyArgs"
+              }
+            }
+          },
+          {
+            "name": "executor",
+            "docs": [
+              "Who is allowed to execute the transaction."
+            ],
+            "type": {
+              "defined": "Executor"
+            }
+          },
+          {
+            "name": "bountyLamports",
+            "docs": [
+              "Lamports escrowed from the rent payer and paid to the executor after execution."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "validAfter",
+            "docs": [
+              "The transaction can't be executed before this slot or timestamp."
+            ],
+            "type": {
+              "option": {
+                "defined": "ValidityBound"
+              }
+            }
+          },
+          {
+            "name": "validUntil",
+            "docs": [
+              "The transaction can't be executed after this slot or timestamp."
+            ],
+            "type": {
+              "option": {
+                "defined": "ValidityBound"
+              }
+            }
+          },
+          {
+            "name": "assertions",
+            "docs": [
+              "Checks on the message accounts once every instruction has been executed."
+            ],
+            "type": {
+              "vec": {
+                "defined": "Assertion"
+              }
+            }
+          },
+          {
+            "name": "captureReturnData",
+            "docs": [
+              "Capture the return data of the executed instructions into the transaction account."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "strict",
+            "docs": [
+              "Fail with `MissingAccount` instead of silently succeeding if the transaction buffer",
+              "passed to `super_transaction_create_from_buffer` doesn't exist."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ApprovalPolicyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "docs": [
+              "Members allowed to approve, reject or cancel the transaction."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Number of member approvals required before execution."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "timeLock",
+            "docs": [
+              "Seconds to wait between approval and execution."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperTransactionExecuteArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "strict",
+            "docs": [
+              "Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperTransactionExecuteRangeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxInstructions",
+            "docs": [
+              "Maximum number of instructions to execute in this call."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "strict",
+            "docs": [
+              "Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "minRemainingCu",
+            "docs": [
+              "Stop before an instruction once fewer compute units than this are left,",
+              "the progress is saved so the next call resumes from there."
             ],
             "type": {
-              "defined": "SuperTransactionMessage"
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperTransactionExecuteBestEffortArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keepAfterExecute",
+            "docs": [
+              "Keep the transaction account with its skipped instructions bitmap instead of closing it."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BatchCreateArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "batchIndex",
+            "docs": [
+              "Index of the batch account to seed the account derivation"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BatchAddTransactionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ephemeralSigners",
+            "docs": [
+              "Number of ephemeral signing PDAs required by the transaction."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "transactionMessage",
+            "type": "bytes"
+          },
+          {
+            "name": "memo",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionPolicyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowedPrograms",
+            "docs": [
+              "If not empty, only these programs can be called."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "deniedPrograms",
+            "docs": [
+              "Programs that can never be called."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ApprovalStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Approved",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Rejected",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Cancelled",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "Vote",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Approve"
+          },
+          {
+            "name": "Reject"
+          },
+          {
+            "name": "Cancel"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Assertion",
+      "docs": [
+        "Check run against an account of the message once every instruction has been executed.",
+        "`account_index` indexes the accounts of the message, static keys first and then the loaded ones."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Lamports",
+            "fields": [
+              {
+                "name": "accountIndex",
+                "type": "u8"
+              },
+              {
+                "name": "min",
+                "type": "u64"
+              },
+              {
+                "name": "max",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "TokenAmount",
+            "fields": [
+              {
+                "name": "accountIndex",
+                "type": "u8"
+              },
+              {
+                "name": "min",
+                "type": "u64"
+              },
+              {
+                "name": "max",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Owner",
+            "fields": [
+              {
+                "name": "accountIndex",
+                "type": "u8"
+              },
+              {
+                "name": "owner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Data",
+            "fields": [
+              {
+                "name": "accountIndex",
+                "type": "u8"
+              },
+              {
+                "name": "offset",
+                "type": "u32"
+              },
+              {
+                "name": "data",
+                "type": "bytes"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "Executor",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Creator"
+          },
+          {
+            "name": "Delegate",
+            "fields": [
+              "publicKey"
+            ]
+          },
+          {
+            "name": "Anyone"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ValidityBound",
+      "docs": [
+        "Bound of the validity window of a `SuperTransaction`, inclusive."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Slot",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "UnixTimestamp",
+            "fields": [
+              "i64"
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "BufferCreated",
+      "fields": [
+        {
+          "name": "transactionBuffer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bufferIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "finalBufferHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "finalBufferSize",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BufferExtended",
+      "fields": [
+        {
+          "name": "transactionBuffer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bufferIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "offset",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "length",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BufferClosed",
+      "fields": [
+        {
+          "name": "transactionBuffer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bufferIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SuperTransactionCreated",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "messageHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "string"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SuperTransactionExecuted",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionsExecuted",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "nextInstructionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "computeUnitsConsumed",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SuperTransactionVoted",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vote",
+          "type": {
+            "defined": "Vote"
+          },
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "ApprovalStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionInvoked",
+      "fields": [
+        {
+          "name": "index",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "programId",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionsSkipped",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "skippedInstructions",
+          "type": "bytes",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BountyPaid",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SuperTransactionClosed",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "DuplicateMember",
+      "msg": "Found multiple members with the same pubkey"
+    },
+    {
+      "code": 6001,
+      "name": "EmptyMembers",
+      "msg": "Members array is empty"
+    },
+    {
+      "code": 6002,
+      "name": "TooManyMembers",
+      "msg": "Too many members, can be up to 65535"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidThreshold",
+      "msg": "Invalid threshold, must be between 1 and number of members with Vote permission"
+    },
+    {
+      "code": 6004,
+      "name": "Unauthorized",
+      "msg": "Attempted to perform an unauthorized action"
+    },
+    {
+      "code": 6005,
+      "name": "NotAMember",
+      "msg": "Provided pubkey is not a member of multisig"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidTransactionMessage",
+      "msg": "TransactionMessage is malformed."
+    },
+    {
+      "code": 6007,
+      "name": "StaleProposal",
+      "msg": "Proposal is stale"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidProposalStatus",
+      "msg": "Invalid proposal status"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidTransactionIndex",
+      "msg": "Invalid transaction index"
+    },
+    {
+      "code": 6010,
+      "name": "AlreadyApproved",
+      "msg": "Member already approved the transaction"
+    },
+    {
+      "code": 6011,
+      "name": "AlreadyRejected",
+      "msg": "Member already rejected the transaction"
+    },
+    {
+      "code": 6012,
+      "name": "AlreadyCancelled",
+      "msg": "Member already cancelled the transaction"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidNumberOfAccounts",
+      "msg": "Wrong number of accounts provided"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidAccount",
+      "msg": "Invalid account provided"
+    },
+    {
+      "code": 6015,
+      "name": "RemoveLastMember",
+      "msg": "Cannot remove last member"
+    },
+    {
+      "code": 6016,
+      "name": "NoVoters",
+      "msg": "Members don't include any voters"
+    },
+    {
+      "code": 6017,
+      "name": "NoProposers",
+      "msg": "Members don't include any proposers"
+    },
+    {
+      "code": 6018,
+      "name": "NoExecutors",
+      "msg": "Members don't include any executors"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidStaleTransactionIndex",
+      "msg": "`stale_transaction_index` must be <= `transaction_index`"
+    },
+    {
+      "code": 6020,
+      "name": "NotSupportedForControlled",
+      "msg": "Instruction not supported for controlled multisig"
+    },
+    {
+      "code": 6021,
+      "name": "TimeLockNotReleased",
+      "msg": "Proposal time lock has not been released"
+    },
+    {
+      "code": 6022,
+      "name": "NoActions",
+      "msg": "Config transaction must have at least one action"
+    },
+    {
+      "code": 6023,
+      "name": "MissingAccount",
+      "msg": "Missing account"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidMint",
+      "msg": "Invalid mint"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidDestination",
+      "msg": "Invalid destination"
+    },
+    {
+      "code": 6026,
+      "name": "SpendingLimitExceeded",
+      "msg": "Spending limit exceeded"
+    },
+    {
+      "code": 6027,
+      "name": "DecimalsMismatch",
+      "msg": "Decimals don't match the mint"
+    },
+    {
+      "code": 6028,
+      "name": "UnknownPermission",
+      "msg": "Member has unknown permission"
+    },
+    {
+      "code": 6029,
+      "name": "ProtectedAccount",
+      "msg": "Account is protected, it cannot be passed into a CPI as writable"
+    },
+    {
+      "code": 6030,
+      "name": "TimeLockExceedsMaxAllowed",
+      "msg": "Time lock exceeds the maximum allowed (90 days)"
+    },
+    {
+      "code": 6031,
+      "name": "IllegalAccountOwner",
+      "msg": "Account is not owned by Multisig program"
+    },
+    {
+      "code": 6032,
+      "name": "RentReclamationDisabled",
+      "msg": "Rent reclamation is disabled for this multisig"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidRentCollector",
+      "msg": "Invalid rent collector address"
+    },
+    {
+      "code": 6034,
+      "name": "ProposalForAnotherMultisig",
+      "msg": "Proposal is for another multisig"
+    },
+    {
+      "code": 6035,
+      "name": "TransactionForAnotherMultisig",
+      "msg": "Transaction is for another multisig"
+    },
+    {
+      "code": 6036,
+      "name": "TransactionNotMatchingProposal",
+      "msg": "Transaction doesn't match proposal"
+    },
+    {
+      "code": 6037,
+      "name": "TransactionNotLastInBatch",
+      "msg": "Transaction is not last in batch"
+    },
+    {
+      "code": 6038,
+      "name": "BatchNotEmpty",
+      "msg": "Batch is not empty"
+    },
+    {
+      "code": 6039,
+      "name": "SpendingLimitInvalidAmount",
+      "msg": "Invalid SpendingLimit amount"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidInstructionArgs",
+      "msg": "Invalid Instruction Arguments"
+    },
+    {
+      "code": 6041,
+      "name": "FinalBufferHashMismatch",
+      "msg": "Final message buffer hash doesnt match the expected hash"
+    },
+    {
+      "code": 6042,
+      "name": "FinalBufferSizeExceeded",
+      "msg": "Final buffer size exceeds the maximum allowed"
+    },
+    {
+      "code": 6043,
+      "name": "FinalBufferSizeMismatch",
+      "msg": "Final buffer size mismatch"
+    },
+    {
+      "code": 6044,
+      "name": "MultisigCreateDeprecated",
+      "msg": "multisig_create has been deprecated. Use multisig_create_v2 instead."
+    },
+    {
+      "code": 6045,
+      "name": "UnalignedBufferWrite",
+      "msg": "Buffer writes must start on a chunk boundary and cover whole chunks"
+    },
+    {
+      "code": 6046,
+      "name": "BufferChunksIncomplete",
+      "msg": "Not every chunk of the transaction buffer has been written"
+    },
+    {
+      "code": 6047,
+      "name": "ChunkProofMismatch",
+      "msg": "Buffer chunk doesn't match the committed chunk Merkle root"
+    },
+    {
+      "code": 6048,
+      "name": "AccountNotLargeEnough",
+      "msg": "Account is too small, grow it with a realloc instruction first"
+    },
+    {
+      "code": 6049,
+      "name": "TransactionTooLarge",
+      "msg": "SuperTransaction exceeds the maximum account size"
+    },
+    {
+      "code": 6050,
+      "name": "TransactionNotPending",
+      "msg": "SuperTransaction message has already been written"
+    },
+    {
+      "code": 6051,
+      "name": "MemoTooLong",
+      "msg": "Memo exceeds the maximum allowed length"
+    },
+    {
+      "code": 6052,
+      "name": "BatchFull",
+      "msg": "Batch cannot hold any more transactions"
+    },
+    {
+      "code": 6053,
+      "name": "ApprovalPolicyMissing",
+      "msg": "Transaction has no approval policy"
+    },
+    {
+      "code": 6054,
+      "name": "BountyNotCovered",
+      "msg": "SuperTransaction lamports don't cover both the bounty and the rent exemption"
+    },
+    {
+      "code": 6055,
+      "name": "TransactionNotYetValid",
+      "msg": "SuperTransaction is not valid yet"
+    },
+    {
+      "code": 6056,
+      "name": "TransactionExpired",
+      "msg": "SuperTransaction has expired"
+    },
+    {
+      "code": 6057,
+      "name": "TransactionNotExpired",
+      "msg": "SuperTransaction has not expired"
+    },
+    {
+      "code": 6058,
+      "name": "AssertionFailed",
+      "msg": "Post-execution assertion failed"
+    },
+    {
+      "code": 6059,
+      "name": "ProgramNotAllowed",
+      "msg": "Instruction calls a program not allowed by the execution policy"
+    },
+    {
+      "code": 6060,
+      "name": "TransactionPending",
+      "msg": "SuperTransaction is pending, complete it with super_transaction_realloc"
+    },
+    {
+      "code": 6061,
+      "name": "TransactionInBatch",
+      "msg": "SuperTransaction belongs to a batch, execute or close it through the batch"
+    },
+    {
+      "code": 6062,
+      "name": "InvalidBatchTransactions",
+      "msg": "Batch transactions passed out of order or not all of them"
+    }
+  ]
+};
+
+export const IDL: SuperTxn = {
+  "version": "0.1.0",
+  "name": "super_txn",
+  "instructions": [
+    {
+      "name": "txnBufferCreate",
+      "docs": [
+        "Create a transaction buffer account."
+      ],
+      "accounts": [
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The member of the multisig that is creating the transaction."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the transaction account rent."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransactionBufferCreateArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "txnBufferClose",
+      "docs": [
+        "Close a transaction buffer account."
+      ],
+      "accounts": [
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The member of the multisig that created the TransactionBuffer.",
+            "Rent gets returned to the creator."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "txnBufferRealloc",
+      "docs": [
+        "Grow a transaction buffer account by up to 10240 bytes towards its final size."
+      ],
+      "accounts": [
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The member of the multisig that created the TransactionBuffer."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the additional rent."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "txnBufferExtend",
+      "docs": [
+        "Extend a transaction buffer account."
+      ],
+      "accounts": [
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransactionBufferExtendArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "txnBufferWriteAt",
+      "docs": [
+        "Write a slice of a transaction buffer account at the given offset.",
+        "Slices can land in any order."
+      ],
+      "accounts": [
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransactionBufferWriteAtArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "txnBufferExecute",
+      "docs": [
+        "Execute the message of a finalized transaction buffer and close the buffer,",
+        "without creating a super transaction."
+      ],
+      "accounts": [
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The creator of the buffer, receives the rent refund."
+          ]
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransactionBufferExecuteArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "superTransactionCreate",
+      "docs": [
+        "Create a new super transaction."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The member of the multisig that is creating the transaction."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the transaction account rent."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SuperTransactionCreateArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "superTransactionCreateFromBuffer",
+      "docs": [
+        "Create a new super transaction from a completed transaction buffer.",
+        "Finalized buffer hash must match `final_buffer_hash`"
+      ],
+      "accounts": [
+        {
+          "name": "superTransactionCreate",
+          "accounts": [
+            {
+              "name": "transaction",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "creator",
+              "isMut": false,
+              "isSigner": true,
+              "docs": [
+                "The member of the multisig that is creating the transaction."
+              ]
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": true,
+              "docs": [
+                "The payer for the transaction account rent."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
             }
+          ]
+        },
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SuperTransactionCreateArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "superTransactionRealloc",
+      "docs": [
+        "Grow a super transaction created from a buffer too large to be written in one instruction.",
+        "Once the account is large enough the message is written and the buffer is closed."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transactionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The creator of the transaction and the transaction buffer."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the additional rent."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "superTransactionExecute",
+      "docs": [
+        "Execute a super transaction.",
+        "Transactions capturing return data are kept open until the creator closes them,",
+        "the last captured return data is set as the return data of this instruction.",
+        "If the transaction has an approval policy it must be `Approved` and its time lock released.",
+        "The executor must be allowed by the transaction `executor`, the rent is refunded to the creator.",
+        "The escrowed bounty is paid to the executor once every instruction has been executed.",
+        "The current slot and timestamp must be within the transaction validity window.",
+        "The transaction assertions are checked after the last instruction.",
+        "Every called program must be allowed by the creator execution policy."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The transaction to execute."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The creator of the transaction, receives the rent refund."
+          ]
+        },
+        {
+          "name": "executor",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The signer executing the transaction, must be allowed by `transaction.executor`.",
+            "Receives the bounty once the transaction is fully executed,",
+            "pays the rent of the captured return data."
+          ]
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SuperTransactionExecuteArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "superTransactionExecuteRange",
+      "docs": [
+        "Execute up to `max_instructions` instructions of a super transaction and save the progress.",
+        "The transaction is closed once its last instruction has been executed."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The transaction to execute."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The creator of the transaction, receives the rent refund."
+          ]
+        },
+        {
+          "name": "executor",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The signer executing the transaction, must be allowed by `transaction.executor`.",
+            "Receives the bounty once the transaction is fully executed,",
+            "pays the rent of the captured return data."
+          ]
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SuperTransactionExecuteRangeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "superTransactionExecuteBestEffort",
+      "docs": [
+        "Execute the remaining instructions of a super transaction, skipping the ones that",
+        "reference missing accounts or a non-executable program instead of failing.",
+        "The skipped instructions are reported in the `InstructionsSkipped` event."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The transaction to execute."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The creator of the transaction, receives the rent refund."
+          ]
+        },
+        {
+          "name": "executor",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The signer executing the transaction, must be allowed by `transaction.executor`.",
+            "Receives the bounty once the transaction is fully executed,",
+            "pays the rent of the captured return data."
+          ]
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SuperTransactionExecuteBestEffortArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "superTransactionApprove",
+      "docs": [
+        "Approve a super transaction on behalf of an approval policy member."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The approval policy member casting the vote."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "superTransactionReject",
+      "docs": [
+        "Reject a super transaction on behalf of an approval policy member."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The approval policy member casting the vote."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "superTransactionCancel",
+      "docs": [
+        "Cancel an approved super transaction on behalf of an approval policy member."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The approval policy member casting the vote."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executionPolicyCreate",
+      "docs": [
+        "Create the execution policy restricting the programs the creator transactions can call."
+      ],
+      "accounts": [
+        {
+          "name": "executionPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the execution policy account rent."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExecutionPolicyArgs"
           }
-        ]
-      }
+        }
+      ]
+    },
+    {
+      "name": "executionPolicyUpdate",
+      "docs": [
+        "Replace the program lists of an execution policy."
+      ],
+      "accounts": [
+        {
+          "name": "executionPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the additional rent, refunded if the policy shrinks."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExecutionPolicyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executionPolicyClose",
+      "docs": [
+        "Close an execution policy, transactions can then call any program but super_txn."
+      ],
+      "accounts": [
+        {
+          "name": "executionPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "batchCreate",
+      "docs": [
+        "Create a batch of super transactions that are executed in order."
+      ],
+      "accounts": [
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The creator of the batch and its transactions."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the rent of the batch and all of its transactions."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BatchCreateArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "batchAddTransaction",
+      "docs": [
+        "Add a super transaction to the end of a batch."
+      ],
+      "accounts": [
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The creator of the batch."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer for the transaction account rent, must be the batch rent payer."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BatchAddTransactionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "batchExecuteNext",
+      "docs": [
+        "Execute the next super transaction of a batch.",
+        "The batch is closed once all of its transactions have been executed."
+      ],
+      "accounts": [
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The next transaction of the batch to execute."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "executionPolicy",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The execution policy of the creator, applied if it has been created."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The batch rent payer, receives the rent of the executed transaction",
+            "and of the batch once it is complete."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "batchClose",
+      "docs": [
+        "Close a batch and its transactions that haven't been executed, in order.",
+        "The rent is refunded to the batch rent payer."
+      ],
+      "accounts": [
+        {
+          "name": "batch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The batch rent payer, receives the rent of the batch and its transactions."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "superTransactionAccountsClose",
+      "docs": [
+        "Closes a `SuperTransaction`"
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "superTransactionCloseExpired",
+      "docs": [
+        "Closes an expired `SuperTransaction`, can be called by anyone.",
+        "The rent is refunded to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The creator of the transaction, receives the rent refund."
+          ]
+        }
+      ],
+      "args": []
     }
   ],
-  "types": [
+  "accounts": [
     {
-      "name": "SuperTransactionMessage",
+      "name": "batch",
+      "docs": [
+        "Groups several `SuperTransaction`s of one creator that are executed in order.",
+        "Batch transactions are derived from the batch key and their index in the batch."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "numSigners",
+            "name": "creator",
             "docs": [
-              "The number of signer pubkeys in the account_keys vec."
+              "Batch creator"
             ],
-            "type": "u8"
+            "type": "publicKey"
           },
           {
-            "name": "numWritableSigners",
+            "name": "index",
             "docs": [
-              "The number of writable signer pubkeys in the account_keys vec."
+              "Index to seed address derivation"
             ],
             "type": "u8"
           },
           {
-            "name": "numWritableNonSigners",
+            "name": "bump",
             "docs": [
-              "The number of writable non-signer pubkeys in the account_keys vec."
+              "PDA bump."
             ],
             "type": "u8"
           },
           {
-            "name": "accountKeys",
+            "name": "rentPayer",
             "docs": [
-              "Unique account pubkeys (including program IDs) required for execution of the tx.",
-              "The signer pubkeys appear at the beginning of the vec, with writable pubkeys first, and read-only pubkeys following.",
-              "The non-signer pubkeys follow with writable pubkeys first and read-only ones following.",
-              "Program IDs are also stored at the end of the vec along with other non-signer non-writable pubkeys:",
-              "",
-              "```plaintext",
-              "[pubkey1, pubkey2, pubkey3, pubkey4, pubkey5, pubkey6, pubkey7, pubkey8]",
-              "|---writable---|  |---readonly---|  |---writable---|  |---readonly---|",
-              "|------------signers-------------|  |----------non-singers-----------|",
-              "```"
+              "Payer of the rent of the batch and its transactions, refunded when they are closed."
             ],
-            "type": {
-              "vec": "publicKey"
-            }
+            "type": "publicKey"
           },
           {
-            "name": "instructions",
+            "name": "size",
             "docs": [
-              "List of instructions making up the tx."
+              "Number of transactions added to the batch."
             ],
-            "type": {
-              "vec": {
-                "defined": "SuperCompiledInstruction"
-              }
-            }
+            "type": "u8"
           },
           {
-            "name": "addressTableLookups",
+            "name": "nextTransactionIndex",
             "docs": [
-              "List of address table lookups used to load additional accounts",
-              "for this transaction."
+              "Index of the next transaction to execute."
             ],
-            "type": {
-              "vec": {
-                "defined": "SuperMessageAddressTableLookup"
-              }
-            }
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "SuperCompiledInstruction",
+      "name": "executionPolicy",
       "docs": [
-        "Concise serialization schema for instructions that make up a transaction.",
-        "Closely mimics the Solana transaction wire format."
+        "Per-creator restriction of the programs the instructions of its transactions can call.",
+        "Without a policy every program but super_txn itself can be called."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "programIdIndex",
-            "type": "u8"
-          },
-          {
-            "name": "accountIndexes",
+            "name": "creator",
             "docs": [
-              "Indices into the tx's `account_keys` list indicating which accounts to pass to the instruction."
+              "Creator the policy applies to."
             ],
-            "type": "bytes"
+            "type": "publicKey"
           },
           {
-            "name": "data",
-            "docs": [
-              "Instruction data."
-            ],
-            "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "SuperMessageAddressTableLookup",
-      "docs": [
-        "Address table lookups describe an on-chain address lookup table to use",
-        "for loading more readonly and writable accounts into a transaction."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "accountKey",
+            "name": "bump",
             "docs": [
-              "Address lookup table account key."
+              "PDA bump."
             ],
-            "type": "publicKey"
+            "type": "u8"
           },
           {
-            "name": "writableIndexes",
+            "name": "allowedPrograms",
             "docs": [
-              "List of indexes used to load writable accounts."
+              "If not empty, only these programs can be called."
             ],
-            "type": "bytes"
+            "type": {
+              "vec": "publicKey"
+            }
           },
           {
-            "name": "readonlyIndexes",
+            "name": "deniedPrograms",
             "docs": [
-              "List of indexes used to load readonly accounts."
+              "Programs that can never be called."
             ],
-            "type": "bytes"
+            "type": {
+              "vec": "publicKey"
+            }
           }
         ]
       }
     },
     {
-      "name": "TransactionBufferCreateArgs",
+      "name": "transactionBuffer",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "txn buffer creator"
+            ],
+            "type": "publicKey"
+          },
           {
             "name": "bufferIndex",
             "docs": [
-              "Index of the buffer account to seed the account derivation"
+              "Index to seed address derivation"
             ],
             "type": "u8"
           },
@@ -499,854 +3721,1374 @@ export type SuperTxn = {
           {
             "name": "finalBufferSize",
             "docs": [
-              "Final size of the buffer."
+              "The size of the final assembled transaction message."
             ],
-            "type": "u16"
+            "type": "u32"
+          },
+          {
+            "name": "chunkMerkleRoot",
+            "docs": [
+              "Optional Merkle root over the `TRANSACTION_BUFFER_CHUNK_SIZE` chunks of the final buffer.",
+              "When set, every chunk must come with a proof and is verified as it is written."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           },
           {
             "name": "buffer",
             "docs": [
-              "Initial slice of the buffer."
+              "The buffer of the transaction message."
             ],
             "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "TransactionBufferExtendArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "buffer",
+            "name": "writtenChunks",
+            "docs": [
+              "Bitmap of the `TRANSACTION_BUFFER_CHUNK_SIZE` chunks of `buffer` that have been written."
+            ],
             "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "SuperTransactionCreateArgs",
+      "name": "superTransaction",
+      "docs": [
+        "Stores data required for tracking the voting and execution status of a super transaction.",
+        "Super transaction is a transaction wraps arbitrary Solana instructions, typically calling into other Solana programs."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "transactionIndex",
+            "name": "creator",
+            "docs": [
+              "SuperTransaction creator"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "Index of the transaction, used to seed the account derivation."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAtSlot",
+            "docs": [
+              "Slot at which the transaction was created."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAtUnix",
+            "docs": [
+              "Unix timestamp at which the transaction was created."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "memo",
+            "docs": [
+              "Optional memo describing the purpose of the transaction, up to `MAX_MEMO_LEN` bytes."
+            ],
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "approval",
+            "docs": [
+              "Optional approval policy, the transaction can only be executed once it is `Approved`."
+            ],
+            "type": {
+              "option": {
+                "defined": "ApprovalPolicy"
+              }
+            }
+          },
+          {
+            "name": "executor",
+            "docs": [
+              "Who is allowed to execute the transaction."
+            ],
+            "type": {
+              "defined": "Executor"
+            }
+          },
+          {
+            "name": "bountyLamports",
+            "docs": [
+              "Lamports escrowed on the account on top of its rent, paid to the executor",
+              "once every instruction has been executed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "validAfter",
+            "docs": [
+              "The transaction can't be executed before this slot or timestamp."
+            ],
+            "type": {
+              "option": {
+                "defined": "ValidityBound"
+              }
+            }
+          },
+          {
+            "name": "validUntil",
+            "docs": [
+              "The transaction can't be executed after this slot or timestamp."
+            ],
+            "type": {
+              "option": {
+                "defined": "ValidityBound"
+              }
+            }
+          },
+          {
+            "name": "assertions",
+            "docs": [
+              "Checked once every instruction has been executed, the execution reverts if any fails."
+            ],
+            "type": {
+              "vec": {
+                "defined": "Assertion"
+              }
+            }
+          },
+          {
+            "name": "ephemeralSignerBumps",
             "docs": [
-              "Index of this transaction belongs to."
+              "Derivation bumps for additional signers.",
+              "Some transactions require multiple signers. Often these additional signers are \"ephemeral\" keypairs",
+              "that are generated on the client with a sole purpose of signing the transaction and be discarded immediately after.",
+              "When wrapping such transactions into multisig ones, we replace these \"ephemeral\" signing keypairs",
+              "with PDAs derived from the MultisigTransaction's `transaction_index` and controlled by the Multisig Program;",
+              "during execution the program includes the seeds of these PDAs into the `invoke_signed` calls,",
+              "thus \"signing\" on behalf of these PDAs."
             ],
-            "type": "u8"
+            "type": "bytes"
           },
           {
-            "name": "ephemeralSigners",
+            "name": "nextInstructionIndex",
             "docs": [
-              "Number of ephemeral signing PDAs required by the transaction."
+              "Index of the next instruction of `message` to execute.",
+              "Only advances when the transaction is executed in several ranges."
             ],
             "type": "u8"
           },
           {
-            "name": "transactionMessage",
+            "name": "skippedInstructions",
+            "docs": [
+              "Bitmap of the instructions skipped by a best-effort execution, one bit per instruction."
+            ],
             "type": "bytes"
           },
           {
-            "name": "memo",
-            "type": {
-              "option": "string"
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "DuplicateMember",
-      "msg": "Found multiple members with the same pubkey"
-    },
-    {
-      "code": 6001,
-      "name": "EmptyMembers",
-      "msg": "Members array is empty"
-    },
-    {
-      "code": 6002,
-      "name": "TooManyMembers",
-      "msg": "Too many members, can be up to 65535"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidThreshold",
-      "msg": "Invalid threshold, must be between 1 and number of members with Vote permission"
-    },
-    {
-      "code": 6004,
-      "name": "Unauthorized",
-      "msg": "Attempted to perform an unauthorized action"
-    },
-    {
-      "code": 6005,
-      "name": "NotAMember",
-      "msg": "Provided pubkey is not a member of multisig"
-    },
-    {
-      "code": 6006,
-      "name": "InvalidTransactionMessage",
-      "msg": "TransactionMessage is malformed."
-    },
-    {
-      "code": 6007,
-      "name": "StaleProposal",
-      "msg": "Proposal is stale"
-    },
-    {
-      "code": 6008,
-      "name": "InvalidProposalStatus",
-      "msg": "Invalid proposal status"
-    },
-    {
-      "code": 6009,
-      "name": "InvalidTransactionIndex",
-      "msg": "Invalid transaction index"
-    },
-    {
-      "code": 6010,
-      "name": "AlreadyApproved",
-      "msg": "Member already approved the transaction"
-    },
-    {
-      "code": 6011,
-      "name": "AlreadyRejected",
-      "msg": "Member already rejected the transaction"
-    },
-    {
-      "code": 6012,
-      "name": "AlreadyCancelled",
-      "msg": "Member already cancelled the transaction"
-    },
-    {
-      "code": 6013,
-      "name": "InvalidNumberOfAccounts",
-      "msg": "Wrong number of accounts provided"
-    },
-    {
-      "code": 6014,
-      "name": "InvalidAccount",
-      "msg": "Invalid account provided"
-    },
-    {
-      "code": 6015,
-      "name": "RemoveLastMember",
-      "msg": "Cannot remove last member"
-    },
-    {
-      "code": 6016,
-      "name": "NoVoters",
-      "msg": "Members don't include any voters"
-    },
-    {
-      "code": 6017,
-      "name": "NoProposers",
-      "msg": "Members don't include any proposers"
-    },
-    {
-      "code": 6018,
-      "name": "NoExecutors",
-      "msg": "Members don't include any executors"
-    },
-    {
-      "code": 6019,
-      "name": "InvalidStaleTransactionIndex",
-      "msg": "`stale_transaction_index` must be <= `transaction_index`"
-    },
-    {
-      "code": 6020,
-      "name": "NotSupportedForControlled",
-      "msg": "Instruction not supported for controlled multisig"
-    },
-    {
-      "code": 6021,
-      "name": "TimeLockNotReleased",
-      "msg": "Proposal time lock has not been released"
-    },
-    {
-      "code": 6022,
-      "name": "NoActions",
-      "msg": "Config transaction must have at least one action"
-    },
-    {
-      "code": 6023,
-      "name": "MissingAccount",
-      "msg": "Missing account"
-    },
-    {
-      "code": 6024,
-      "name": "InvalidMint",
-      "msg": "Invalid mint"
-    },
-    {
-      "code": 6025,
-      "name": "InvalidDestination",
-      "msg": "Invalid destination"
-    },
-    {
-      "code": 6026,
-      "name": "SpendingLimitExceeded",
-      "msg": "Spending limit exceeded"
-    },
-    {
-      "code": 6027,
-      "name": "DecimalsMismatch",
-      "msg": "Decimals don't match the mint"
-    },
-    {
-      "code": 6028,
-      "name": "UnknownPermission",
-      "msg": "Member has unknown permission"
-    },
-    {
-      "code": 6029,
-      "name": "ProtectedAccount",
-      "msg": "Account is protected, it cannot be passed into a CPI as writable"
-    },
-    {
-      "code": 6030,
-      "name": "TimeLockExceedsMaxAllowed",
-      "msg": "Time lock exceeds the maximum allowed (90 days)"
-    },
-    {
-      "code": 6031,
-      "name": "IllegalAccountOwner",
-      "msg": "Account is not owned by Multisig program"
-    },
-    {
-      "code": 6032,
-      "name": "RentReclamationDisabled",
-      "msg": "Rent reclamation is disabled for this multisig"
-    },
-    {
-      "code": 6033,
-      "name": "InvalidRentCollector",
-      "msg": "Invalid rent collector address"
-    },
-    {
-      "code": 6034,
-      "name": "ProposalForAnotherMultisig",
-      "msg": "Proposal is for another multisig"
-    },
-    {
-      "code": 6035,
-      "name": "TransactionForAnotherMultisig",
-      "msg": "Transaction is for another multisig"
-    },
-    {
-      "code": 6036,
-      "name": "TransactionNotMatchingProposal",
-      "msg": "Transaction doesn't match proposal"
-    },
-    {
-      "code": 6037,
-      "name": "TransactionNotLastInBatch",
-      "msg": "Transaction is not last in batch"
-    },
-    {
-      "code": 6038,
-      "name": "BatchNotEmpty",
-      "msg": "Batch is not empty"
-    },
-    {
-      "code": 6039,
-      "name": "SpendingLimitInvalidAmount",
-      "msg": "Invalid SpendingLimit amount"
-    },
-    {
-      "code": 6040,
-      "name": "InvalidInstructionArgs",
-      "msg": "Invalid Instruction Arguments"
-    },
-    {
-      "code": 6041,
-      "name": "FinalBufferHashMismatch",
-      "msg": "Final message buffer hash doesnt match the expected hash"
-    },
-    {
-      "code": 6042,
-      "name": "FinalBufferSizeExceeded",
-      "msg": "Final buffer size cannot exceed 4000 bytes"
-    },
-    {
-      "code": 6043,
-      "name": "FinalBufferSizeMismatch",
-      "msg": "Final buffer size mismatch"
-    },
-    {
-      "code": 6044,
-      "name": "MultisigCreateDeprecated",
-      "msg": "multisig_create has been deprecated. Use multisig_create_v2 instead."
+            "name": "captureReturnData",
+            "docs": [
+              "Capture the return data of the executed instructions into `return_data`.",
+              "The account is then kept open after execution until the creator closes it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "returnData",
+            "docs": [
+              "Return data captured from the executed instructions, in execution order."
+            ],
+            "type": {
+              "vec": {
+                "defined": "InstructionReturnData"
+              }
+            }
+          },
+          {
+            "name": "computeUnits",
+            "docs": [
+              "Compute units consumed by the executed instructions, in execution order.",
+              "Only recorded along with `return_data` when `capture_return_data` is set."
+            ],
+            "type": {
+              "vec": {
+                "defined": "InstructionComputeUnits"
+              }
+            }
+          },
+          {
+            "name": "pendingMessageHash",
+            "docs": [
+              "`final_buffer_hash` of the buffer holding the message while the transaction is pending,",
+              "only that buffer can complete it with `super_transaction_realloc`."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "batch",
+            "docs": [
+              "The batch the transaction was added to, it can then only be executed and closed",
+              "through the batch, in order."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "message",
+            "docs": [
+              "data required for executing the transaction."
+            ],
+            "type": {
+              "defined": "SuperTransactionMessage"
+            }
+          }
+        ]
+      }
     }
-  ]
-};
-
-export const IDL: SuperTxn = {
-  "version": "0.1.0",
-  "name": "super_txn",
-  "instructions": [
+  ],
+  "types": [
     {
-      "name": "txnBufferCreate",
+      "name": "ApprovalPolicy",
       "docs": [
-        "Create a transaction buffer account."
-      ],
-      "accounts": [
-        {
-          "name": "transactionBuffer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "creator",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "The member of the multisig that is creating the transaction."
-          ]
-        },
-        {
-          "name": "rentPayer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "The payer for the transaction account rent."
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+        "Optional set of members that have to approve a `SuperTransaction` before it can be executed."
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "TransactionBufferCreateArgs"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "docs": [
+              "Members allowed to vote on the transaction."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Number of approvals required for the transaction to be `Approved`."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "timeLock",
+            "docs": [
+              "Number of seconds that must pass between approval and execution."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Current status of the transaction."
+            ],
+            "type": {
+              "defined": "ApprovalStatus"
+            }
+          },
+          {
+            "name": "approved",
+            "docs": [
+              "Members that approved the transaction."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "rejected",
+            "docs": [
+              "Members that rejected the transaction."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Members that cancelled the approved transaction."
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "txnBufferClose",
+      "name": "InstructionReturnData",
       "docs": [
-        "Close a transaction buffer account."
-      ],
-      "accounts": [
-        {
-          "name": "transactionBuffer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "creator",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "The member of the multisig that created the TransactionBuffer."
-          ]
-        }
+        "Return data set by a wrapped instruction."
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "docs": [
+              "Index of the instruction in the message."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "programId",
+            "docs": [
+              "Program that set the return data."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
     },
     {
-      "name": "txnBufferExtend",
+      "name": "InstructionComputeUnits",
       "docs": [
-        "Extend a transaction buffer account."
-      ],
-      "accounts": [
-        {
-          "name": "transactionBuffer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "creator",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "The member of the multisig that created the TransactionBuffer."
-          ]
-        }
+        "Compute units consumed by a wrapped instruction."
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "TransactionBufferExtendArgs"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "docs": [
+              "Index of the instruction in the message."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "units",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperTransactionMessage",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "numSigners",
+            "docs": [
+              "The number of signer pubkeys in the account_keys vec."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "numWritableSigners",
+            "docs": [
+              "The number of writable signer pubkeys in the account_keys vec."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "numWritableNonSigners",
+            "docs": [
+              "The number of writable non-signer pubkeys in the account_keys vec."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "accountKeys",
+            "docs": [
+              "Unique account pubkeys (including program IDs) required for execution of the tx.",
+              "The signer pubkeys appear at the beginning of the vec, with writable pubkeys first, and read-only pubkeys following.",
+              "The non-signer pubkeys follow with writable pubkeys first and read-only ones following.",
+              "Program IDs are also stored at the end of the vec along with other non-signer non-writable pubkeys:",
+              "",
+              "```plaintext",
+              "[pubkey1, pubkey2, pubkey3, pubkey4, pubkey5, pubkey6, pubkey7, pubkey8]",
+              "|---writable---|  |---readonly---|  |---writable---|  |---readonly---|",
+              "|------------signers-------------|  |----------non-signers-----------|",
+              "```"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "instructions",
+            "docs": [
+              "List of instructions making up the tx."
+            ],
+            "type": {
+              "vec": {
+                "defined": "SuperCompiledInstruction"
+              }
+            }
+          },
+          {
+            "name": "addressTableLookups",
+            "docs": [
+              "List of address table lookups used to load additional accounts",
+              "for this transaction."
+            ],
+            "type": {
+              "vec": {
+                "defined": "SuperMessageAddressTableLookup"
+              }
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "superTransactionCreate",
+      "name": "SuperCompiledInstruction",
       "docs": [
-        "Create a new super transaction."
-      ],
-      "accounts": [
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "creator",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "The member of the multisig that is creating the transaction."
-          ]
-        },
-        {
-          "name": "rentPayer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "The payer for the transaction account rent."
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+        "Concise serialization schema for instructions that make up a transaction.",
+        "Closely mimics the Solana transaction wire format."
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SuperTransactionCreateArgs"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programIdIndex",
+            "type": "u8"
+          },
+          {
+            "name": "accountIndexes",
+            "docs": [
+              "Indices into the tx's `account_keys` list indicating which accounts to pass to the instruction."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Instruction data."
+            ],
+            "type": "bytes"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "superTransactionCreateFromBuffer",
+      "name": "SuperMessageAddressTableLookup",
       "docs": [
-        "Create a new super transaction from a completed transaction buffer.",
-        "Finalized buffer hash must match `final_buffer_hash`"
-      ],
-      "accounts": [
-        {
-          "name": "superTransactionCreate",
-          "accounts": [
-            {
-              "name": "transaction",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "creator",
-              "isMut": false,
-              "isSigner": true,
-              "docs": [
-                "The member of the multisig that is creating the transaction."
-              ]
-            },
-            {
-              "name": "rentPayer",
-              "isMut": true,
-              "isSigner": true,
-              "docs": [
-                "The payer for the transaction account rent."
-              ]
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "transactionBuffer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        }
+        "Address table lookups describe an on-chain address lookup table to use",
+        "for loading more readonly and writable accounts into a transaction."
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SuperTransactionCreateArgs"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "accountKey",
+            "docs": [
+              "Address lookup table account key."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "writableIndexes",
+            "docs": [
+              "List of indexes used to load writable accounts."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "readonlyIndexes",
+            "docs": [
+              "List of indexes used to load readonly accounts."
+            ],
+            "type": "bytes"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "superTransactionExecute",
-      "docs": [
-        "Execute a super transaction.",
-        "The transaction must be `Approved`."
-      ],
-      "accounts": [
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The transaction to execute."
-          ]
-        },
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": []
+      "name": "TransactionBufferCreateArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bufferIndex",
+            "docs": [
+              "Index of the buffer account to seed the account derivation"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "finalBufferHash",
+            "docs": [
+              "Hash of the final assembled transaction message."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "finalBufferSize",
+            "docs": [
+              "Final size of the buffer."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "buffer",
+            "docs": [
+              "Initial slice of the buffer."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "chunkMerkleRoot",
+            "docs": [
+              "Optional Merkle root over the chunks of the final buffer.",
+              "When set, the initial slice must be empty and every chunk is uploaded with a proof."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "superTransactionAccountsClose",
-      "docs": [
-        "Closes a `SuperTransaction`"
-      ],
-      "accounts": [
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "SuperTransaction corresponding to the `proposal`."
-          ]
-        },
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "name": "TransactionBufferExtendArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buffer",
+            "type": "bytes"
+          },
+          {
+            "name": "chunkProof",
+            "type": {
+              "option": {
+                "vec": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            }
+          },
+          {
+            "name": "strict",
+            "type": "bool"
+          }
+        ]
+      }
+    },
     {
-      "name": "transactionBuffer",
+      "name": "TransactionBufferWriteAtArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "creator",
+            "name": "offset",
             "docs": [
-              "txn buffer creator"
+              "Offset into the final buffer, must be a multiple of `TRANSACTION_BUFFER_CHUNK_SIZE`."
             ],
-            "type": "publicKey"
+            "type": "u32"
           },
           {
-            "name": "bufferIndex",
+            "name": "bytes",
             "docs": [
-              "Index to seed address derivation"
+              "Bytes to write at `offset`."
             ],
-            "type": "u8"
+            "type": "bytes"
           },
           {
-            "name": "finalBufferHash",
+            "name": "chunkProof",
             "docs": [
-              "Hash of the final assembled transaction message."
+              "Merkle proof of the written chunk, required if the buffer has a `chunk_merkle_root`."
             ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "option": {
+                "vec": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
             }
           },
           {
-            "name": "finalBufferSize",
+            "name": "strict",
             "docs": [
-              "The size of the final assembled transaction message."
+              "Fail with `MissingAccount` instead of silently succeeding if the buffer doesn't exist."
             ],
-            "type": "u16"
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransactionBufferExecuteArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ephemeralSigners",
+            "docs": [
+              "Number of ephemeral signing PDAs required by the message, derived from the buffer key."
+            ],
+            "type": "u8"
           },
           {
-            "name": "buffer",
+            "name": "strict",
             "docs": [
-              "The buffer of the transaction message."
+              "Fail with `MissingAccount` instead of silently succeeding if the buffer doesn't exist."
             ],
-            "type": "bytes"
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "superTransaction",
-      "docs": [
-        "Stores data required for tracking the voting and execution status of a super transaction.",
-        "Super transaction is a transaction wraps arbitrary Solana instructions, typically calling into other Solana programs."
-      ],
+      "name": "SuperTransactionCreateArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "creator",
+            "name": "transactionIndex",
             "docs": [
-              "SuperTransaction creator"
+              "Index of this transaction belongs to."
             ],
-            "type": "publicKey"
+            "type": "u8"
           },
           {
-            "name": "ephemeralSignerBumps",
+            "name": "ephemeralSigners",
             "docs": [
-              "Index of this transaction within the bundle. (0 if it is not)",
-              "Derivation bumps for additional signers.",
-              "Some transactions require multiple signers. Often these additional signers are \"ephemeral\" keypairs",
-              "that are generated on the client with a sole purpose of signing the transaction and be discarded immediately after.",
-              "When wrapping such transactions into multisig ones, we replace these \"ephemeral\" signing keypairs",
-              "with PDAs derived from the MultisigTransaction's `transaction_index` and controlled by the Multisig Program;",
-              "during execution the program includes the seeds of these PDAs into the `invoke_signed` calls,",
-              "thus \"signing\" on behalf of these PDAs."
+              "Number of ephemeral signing PDAs required by the transaction."
             ],
+            "type": "u8"
+          },
+          {
+            "name": "transactionMessage",
             "type": "bytes"
           },
           {
-            "name": "message",
+            "name": "memo",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "approval",
             "docs": [
-              "data required for executing the transaction."
+              "Optional approval policy the transaction has to satisfy before it can be executed."
             ],
             "type": {
-              "defined": "SuperTransactionMessage"
+              "option": {
+                "defined": "ApprovalPolicyArgs"
+              }
             }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "SuperTransactionMessage",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "numSigners",
+            "name": "executor",
             "docs": [
-              "The number of signer pubkeys in the account_keys vec."
+              "Who is allowed to execute the transaction."
             ],
-            "type": "u8"
+            "type": {
+              "defined": "Executor"
+            }
           },
           {
-            "name": "numWritableSigners",
+            "name": "bountyLamports",
             "docs": [
-              "The number of writable signer pubkeys in the account_keys vec."
+              "Lamports escrowed from the rent payer and paid to the executor after execution."
             ],
-            "type": "u8"
+            "type": "u64"
           },
           {
-            "name": "numWritableNonSigners",
+            "name": "validAfter",
             "docs": [
-              "The number of writable non-signer pubkeys in the account_keys vec."
+              "The transaction can't be executed before this slot or timestamp."
             ],
-            "type": "u8"
+            "type": {
+              "option": {
+                "defined": "ValidityBound"
+              }
+            }
           },
           {
-            "name": "accountKeys",
+            "name": "validUntil",
             "docs": [
-              "Unique account pubkeys (including program IDs) required for execution of the tx.",
-              "The signer pubkeys appear at the beginning of the vec, with writable pubkeys first, and read-only pubkeys following.",
-              "The non-signer pubkeys follow with writable pubkeys first and read-only ones following.",
-              "Program IDs are also stored at the end of the vec along with other non-signer non-writable pubkeys:",
-              "",
-              "```plaintext",
-              "[pubkey1, pubkey2, pubkey3, pubkey4, pubkey5, pubkey6, pubkey7, pubkey8]",
-              "|---writable---|  |---readonly---|  |---writable---|  |---readonly---|",
-              "|------------signers-------------|  |----------non-singers-----------|",
-              "```"
+              "The transaction can't be executed after this slot or timestamp."
             ],
             "type": {
-              "vec": "publicKey"
+              "option": {
+                "defined": "ValidityBound"
+              }
             }
           },
           {
-            "name": "instructions",
+            "name": "assertions",
             "docs": [
-              "List of instructions making up the tx."
+              "Checks on the message accounts once every instruction has been executed."
             ],
             "type": {
               "vec": {
-                "defined": "SuperCompiledInstruction"
+                "defined": "Assertion"
               }
             }
           },
           {
-            "name": "addressTableLookups",
+            "name": "captureReturnData",
             "docs": [
-              "List of address table lookups used to load additional accounts",
-              "for this transaction."
+              "Capture the return data of the executed instructions into the transaction account."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "strict",
+            "docs": [
+              "Fail with `MissingAccount` instead of silently succeeding if the transaction buffer",
+              "passed to `super_transaction_create_from_buffer` doesn't exist."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ApprovalPolicyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "docs": [
+              "Members allowed to approve, reject or cancel the transaction."
             ],
             "type": {
-              "vec": {
-                "defined": "SuperMessageAddressTableLookup"
-              }
+              "vec": "publicKey"
             }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Number of member approvals required before execution."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "timeLock",
+            "docs": [
+              "Seconds to wait between approval and execution."
+            ],
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "SuperCompiledInstruction",
-      "docs": [
-        "Concise serialization schema for instructions that make up a transaction.",
-        "Closely mimics the Solana transaction wire format."
-      ],
+      "name": "SuperTransactionExecuteArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "programIdIndex",
+            "name": "strict",
+            "docs": [
+              "Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperTransactionExecuteRangeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxInstructions",
+            "docs": [
+              "Maximum number of instructions to execute in this call."
+            ],
             "type": "u8"
           },
           {
-            "name": "accountIndexes",
+            "name": "strict",
             "docs": [
-              "Indices into the tx's `account_keys` list indicating which accounts to pass to the instruction."
+              "Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist."
             ],
-            "type": "bytes"
+            "type": "bool"
           },
           {
-            "name": "data",
+            "name": "minRemainingCu",
             "docs": [
-              "Instruction data."
+              "Stop before an instruction once fewer compute units than this are left,",
+              "the progress is saved so the next call resumes from there."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SuperTransactionExecuteBestEffortArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keepAfterExecute",
+            "docs": [
+              "Keep the transaction account with its skipped instructions bitmap instead of closing it."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BatchCreateArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "batchIndex",
+            "docs": [
+              "Index of the batch account to seed the account derivation"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BatchAddTransactionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ephemeralSigners",
+            "docs": [
+              "Number of ephemeral signing PDAs required by the transaction."
             ],
+            "type": "u8"
+          },
+          {
+            "name": "transactionMessage",
             "type": "bytes"
+          },
+          {
+            "name": "memo",
+            "type": {
+              "option": "string"
+            }
           }
         ]
       }
     },
     {
-      "name": "SuperMessageAddressTableLookup",
-      "docs": [
-        "Address table lookups describe an on-chain address lookup table to use",
-        "for loading more readonly and writable accounts into a transaction."
-      ],
+      "name": "ExecutionPolicyArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "accountKey",
+            "name": "allowedPrograms",
             "docs": [
-              "Address lookup table account key."
+              "If not empty, only these programs can be called."
             ],
-            "type": "publicKey"
+            "type": {
+              "vec": "publicKey"
+            }
           },
           {
-            "name": "writableIndexes",
+            "name": "deniedPrograms",
             "docs": [
-              "List of indexes used to load writable accounts."
+              "Programs that can never be called."
             ],
-            "type": "bytes"
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ApprovalStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Approved",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Rejected",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
           },
           {
-            "name": "readonlyIndexes",
-            "docs": [
-              "List of indexes used to load readonly accounts."
-            ],
-            "type": "bytes"
+            "name": "Cancelled",
+            "fields": [
+              {
+                "name": "timestamp",
+                "type": "i64"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "TransactionBufferCreateArgs",
+      "name": "Vote",
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bufferIndex",
-            "docs": [
-              "Index of the buffer account to seed the account derivation"
-            ],
-            "type": "u8"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "finalBufferHash",
-            "docs": [
-              "Hash of the final assembled transaction message."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "Approve"
           },
           {
-            "name": "finalBufferSize",
-            "docs": [
-              "Final size of the buffer."
-            ],
-            "type": "u16"
+            "name": "Reject"
           },
           {
-            "name": "buffer",
-            "docs": [
-              "Initial slice of the buffer."
-            ],
-            "type": "bytes"
+            "name": "Cancel"
           }
         ]
       }
     },
     {
-      "name": "TransactionBufferExtendArgs",
+      "name": "Assertion",
+      "docs": [
+        "Check run against an account of the message once every instruction has been executed.",
+        "`account_index` indexes the accounts of the message, static keys first and then the loaded ones."
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "buffer",
-            "type": "bytes"
+            "name": "Lamports",
+            "fields": [
+              {
+                "name": "accountIndex",
+                "type": "u8"
+              },
+              {
+                "name": "min",
+                "type": "u64"
+              },
+              {
+                "name": "max",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "TokenAmount",
+            "fields": [
+              {
+                "name": "accountIndex",
+                "type": "u8"
+              },
+              {
+                "name": "min",
+                "type": "u64"
+              },
+              {
+                "name": "max",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Owner",
+            "fields": [
+              {
+                "name": "accountIndex",
+                "type": "u8"
+              },
+              {
+                "name": "owner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Data",
+            "fields": [
+              {
+                "name": "accountIndex",
+                "type": "u8"
+              },
+              {
+                "name": "offset",
+                "type": "u32"
+              },
+              {
+                "name": "data",
+                "type": "bytes"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "SuperTransactionCreateArgs",
+      "name": "Executor",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "transactionIndex",
-            "docs": [
-              "Index of this transaction belongs to."
-            ],
-            "type": "u8"
+            "name": "Creator"
           },
           {
-            "name": "ephemeralSigners",
-            "docs": [
-              "Number of ephemeral signing PDAs required by the transaction."
-            ],
-            "type": "u8"
+            "name": "Delegate",
+            "fields": [
+              "publicKey"
+            ]
           },
           {
-            "name": "transactionMessage",
-            "type": "bytes"
+            "name": "Anyone"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ValidityBound",
+      "docs": [
+        "Bound of the validity window of a `SuperTransaction`, inclusive."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Slot",
+            "fields": [
+              "u64"
+            ]
           },
           {
-            "name": "memo",
-            "type": {
-              "option": "string"
-            }
+            "name": "UnixTimestamp",
+            "fields": [
+              "i64"
+            ]
           }
         ]
       }
     }
   ],
+  "events": [
+    {
+      "name": "BufferCreated",
+      "fields": [
+        {
+          "name": "transactionBuffer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bufferIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "finalBufferHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "finalBufferSize",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BufferExtended",
+      "fields": [
+        {
+          "name": "transactionBuffer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bufferIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "offset",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "length",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BufferClosed",
+      "fields": [
+        {
+          "name": "transactionBuffer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bufferIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SuperTransactionCreated",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "messageHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "string"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SuperTransactionExecuted",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionsExecuted",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "nextInstructionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "computeUnitsConsumed",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SuperTransactionVoted",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vote",
+          "type": {
+            "defined": "Vote"
+          },
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "ApprovalStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionInvoked",
+      "fields": [
+        {
+          "name": "index",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "programId",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionsSkipped",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "skippedInstructions",
+          "type": "bytes",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BountyPaid",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SuperTransactionClosed",
+      "fields": [
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    }
+  ],
   "errors": [
     {
       "code": 6000,
@@ -1561,7 +5303,7 @@ export const IDL: SuperTxn = {
     {
       "code": 6042,
       "name": "FinalBufferSizeExceeded",
-      "msg": "Final buffer size cannot exceed 4000 bytes"
+      "msg": "Final buffer size exceeds the maximum allowed"
     },
     {
       "code": 6043,
@@ -1572,6 +5314,96 @@ export const IDL: SuperTxn = {
       "code": 6044,
       "name": "MultisigCreateDeprecated",
       "msg": "multisig_create has been deprecated. Use multisig_create_v2 instead."
+    },
+    {
+      "code": 6045,
+      "name": "UnalignedBufferWrite",
+      "msg": "Buffer writes must start on a chunk boundary and cover whole chunks"
+    },
+    {
+      "code": 6046,
+      "name": "BufferChunksIncomplete",
+      "msg": "Not every chunk of the transaction buffer has been written"
+    },
+    {
+      "code": 6047,
+      "name": "ChunkProofMismatch",
+      "msg": "Buffer chunk doesn't match the committed chunk Merkle root"
+    },
+    {
+      "code": 6048,
+      "name": "AccountNotLargeEnough",
+      "msg": "Account is too small, grow it with a realloc instruction first"
+    },
+    {
+      "code": 6049,
+      "name": "TransactionTooLarge",
+      "msg": "SuperTransaction exceeds the maximum account size"
+    },
+    {
+      "code": 6050,
+      "name": "TransactionNotPending",
+      "msg": "SuperTransaction message has already been written"
+    },
+    {
+      "code": 6051,
+      "name": "MemoTooLong",
+      "msg": "Memo exceeds the maximum allowed length"
+    },
+    {
+      "code": 6052,
+      "name": "BatchFull",
+      "msg": "Batch cannot hold any more transactions"
+    },
+    {
+      "code": 6053,
+      "name": "ApprovalPolicyMissing",
+      "msg": "Transaction has no approval policy"
+    },
+    {
+      "code": 6054,
+      "name": "BountyNotCovered",
+      "msg": "SuperTransaction lamports don't cover both the bounty and the rent exemption"
+    },
+    {
+      "code": 6055,
+      "name": "TransactionNotYetValid",
+      "msg": "SuperTransaction is not valid yet"
+    },
+    {
+      "code": 6056,
+      "name": "TransactionExpired",
+      "msg": "SuperTransaction has expired"
+    },
+    {
+      "code": 6057,
+      "name": "TransactionNotExpired",
+      "msg": "SuperTransaction has not expired"
+    },
+    {
+      "code": 6058,
+      "name": "AssertionFailed",
+      "msg": "Post-execution assertion failed"
+    },
+    {
+      "code": 6059,
+      "name": "ProgramNotAllowed",
+      "msg": "Instruction calls a program not allowed by the execution policy"
+    },
+    {
+      "code": 6060,
+      "name": "TransactionPending",
+      "msg": "SuperTransaction is pending, complete it with super_transaction_realloc"
+    },
+    {
+      "code": 6061,
+      "name": "TransactionInBatch",
+      "msg": "SuperTransaction belongs to a batch, execute or close it through the batch"
+    },
+    {
+      "code": 6062,
+      "name": "InvalidBatchTransactions",
+      "msg": "Batch transactions passed out of order or not all of them"
     }
   ]
 };
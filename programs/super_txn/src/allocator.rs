@@ -1,47 +1,86 @@
 #![allow(dead_code)]
 
 use std::alloc::{GlobalAlloc, Layout};
+use std::mem::size_of;
+use std::ptr::null_mut;
 
 use anchor_lang::solana_program;
+use anchor_lang::solana_program::log::{sol_log, sol_log_64};
 
-/// The end of the region where heap space may be reserved for the program.
+/// Size of the heap the allocator hands out, selected with the `heap-64k`, `heap-128k`
+/// and `heap-256k` features, 32KiB by default.
 ///
-/// The actual size of the heap is currently not available at runtime.
-pub const HEAP_END_ADDRESS: usize = 0x400000000;
+/// The actual size of the heap is not available at runtime, transactions calling
+/// the program must request at least this much with `ComputeBudgetInstruction::request_heap_frame`.
+#[cfg(feature = "heap-256k")]
+pub const HEAP_SIZE: usize = 256 * 1024;
+#[cfg(all(feature = "heap-128k", not(feature = "heap-256k")))]
+pub const HEAP_SIZE: usize = 128 * 1024;
+#[cfg(all(
+    feature = "heap-64k",
+    not(any(feature = "heap-128k", feature = "heap-256k"))
+))]
+pub const HEAP_SIZE: usize = 64 * 1024;
+#[cfg(not(any(feature = "heap-64k", feature = "heap-128k", feature = "heap-256k")))]
+pub const HEAP_SIZE: usize = solana_program::entrypoint::HEAP_LENGTH;
 
-#[cfg(not(feature = "no-entrypoint"))]
+#[cfg(all(target_os = "solana", not(feature = "no-entrypoint")))]
 #[global_allocator]
-pub static ALLOCATOR: BumpAllocator = BumpAllocator {};
+pub static ALLOCATOR: BumpAllocator = BumpAllocator::new(
+    solana_program::entrypoint::HEAP_START_ADDRESS as usize,
+    HEAP_SIZE,
+);
 
+/// Peak heap usage, including the memory freed by `scoped`.
 pub fn heap_used() -> usize {
-    #[cfg(not(feature = "no-entrypoint"))]
+    #[cfg(all(target_os = "solana", not(feature = "no-entrypoint")))]
     return ALLOCATOR.used();
 
-    #[cfg(feature = "no-entrypoint")]
+    #[cfg(not(all(target_os = "solana", not(feature = "no-entrypoint"))))]
     return 0;
 }
 
+/// Runs `f` and frees everything it allocated if it succeeds, see `BumpAllocator::scoped`.
+pub fn scoped<T: Copy, E>(f: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+    #[cfg(all(target_os = "solana", not(feature = "no-entrypoint")))]
+    return ALLOCATOR.scoped(f);
+
+    #[cfg(not(all(target_os = "solana", not(feature = "no-entrypoint"))))]
+    return f();
+}
+
 /// Custom bump allocator for on-chain operations
 ///
 /// The default allocator is also a bump one, but grows from a fixed
 /// HEAP_START + 32kb downwards and has no way of making use of extra
 /// heap space requested for the transaction.
 ///
-/// This implementation starts at HEAP_START and grows upward, producing
-/// a segfault once out of available heap memory.
-pub struct BumpAllocator {}
+/// This implementation starts at `start` and grows upward, up to `len` bytes.
+/// Once out of heap memory it logs the failed allocation and returns null,
+/// which aborts the program.
+///
+/// The current position, the peak position and the start of the innermost scope
+/// are stored in the first words of the region, which must be zeroed initially.
+pub struct BumpAllocator {
+    start: usize,
+    len: usize,
+}
+
+const POS_OFFSET: usize = 0;
+const PEAK_OFFSET: usize = size_of::<usize>();
+const SCOPE_OFFSET: usize = 2 * size_of::<usize>();
+const STATE_LEN: usize = 3 * size_of::<usize>();
 
 unsafe impl GlobalAlloc for BumpAllocator {
     #[inline]
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        let heap_start = solana_program::entrypoint::HEAP_START_ADDRESS as usize;
-        let pos_ptr = heap_start as *mut usize;
+        let pos_ptr = self.state(POS_OFFSET);
 
         let mut pos = *pos_ptr;
         if pos == 0 {
-            // First time, override the current position to be just past the location
-            // where the current heap position is stored.
-            pos = heap_start + 8;
+            // First time, override the current position to be just past the locations
+            // where the allocator state is stored.
+            pos = self.start + STATE_LEN;
         }
 
         // The result address needs to be aligned to layout.align(),
@@ -51,37 +90,200 @@ unsafe impl GlobalAlloc for BumpAllocator {
         let mask = layout.align().wrapping_sub(1);
         let begin = pos.wrapping_add(mask) & (!mask);
 
+        // Ensure allocations can't escape the requested heap frame.
+        // Logging can't allocate here, hence the raw syscalls.
+        let end = match begin.checked_add(layout.size()) {
+            Some(end) if end <= self.start + self.len => end,
+            _ => {
+                sol_log("Error: out of heap memory, allocation size, heap used and heap size:");
+                sol_log_64(
+                    layout.size() as u64,
+                    (pos - self.start) as u64,
+                    self.len as u64,
+                    0,
+                    0,
+                );
+                return null_mut();
+            }
+        };
+
         // Update allocator state
-        let end = begin.checked_add(layout.size()).unwrap();
         *pos_ptr = end;
 
-        // Ensure huge allocations can't escape the dedicated heap memory region
-        assert!(end < HEAP_END_ADDRESS);
-
-        // Write a byte to trigger heap overflow errors early
-        let end_ptr = end as *mut u8;
-        *end_ptr = 0;
-
         begin as *mut u8
     }
     #[inline]
     unsafe fn dealloc(&self, _: *mut u8, _: Layout) {
         // I'm a bump allocator, I don't free
     }
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // Growing memory allocated before the innermost scope would move it into the scope,
+        // where it is freed on rollback.
+        debug_assert!(
+            ptr as usize >= *self.state(SCOPE_OFFSET),
+            "memory allocated before a heap scope was reallocated inside it"
+        );
+
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+        let new_ptr = self.alloc(new_layout);
+        if !new_ptr.is_null() {
+            core::ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+        }
+        new_ptr
+    }
 }
 
 impl BumpAllocator {
+    /// Allocator handing out the `len` bytes starting at `start`.
+    pub const fn new(start: usize, len: usize) -> Self {
+        Self { start, len }
+    }
+
+    #[inline]
+    fn state(&self, offset: usize) -> *mut usize {
+        (self.start + offset) as *mut usize
+    }
+
+    /// Peak heap usage, the current position or the highest one rolled back from.
     #[inline]
     pub fn used(&self) -> usize {
-        let heap_start = solana_program::entrypoint::HEAP_START_ADDRESS as usize;
         unsafe {
-            let pos_ptr = heap_start as *mut usize;
-
-            let pos = *pos_ptr;
+            let pos = (*self.state(POS_OFFSET)).max(*self.state(PEAK_OFFSET));
             if pos == 0 {
                 return 0;
             }
-            return pos - heap_start;
+            pos - self.start
+        }
+    }
+
+    /// Current heap position, to be restored with `rollback`.
+    #[inline]
+    pub fn checkpoint(&self) -> usize {
+        unsafe { *self.state(POS_OFFSET) }
+    }
+
+    /// Frees everything allocated since `checkpoint`.
+    ///
+    /// # Safety
+    /// Nothing allocated since `checkpoint` can be used afterwards.
+    #[inline]
+    pub unsafe fn rollback(&self, checkpoint: usize) {
+        let pos_ptr = self.state(POS_OFFSET);
+        let peak_ptr = self.state(PEAK_OFFSET);
+
+        let pos = *pos_ptr;
+        // Checkpoints must be rolled back innermost first.
+        debug_assert!(pos >= checkpoint);
+        *peak_ptr = (*peak_ptr).max(pos);
+        *pos_ptr = checkpoint;
+
+        // Catch memory escaping the scope by clobbering it.
+        #[cfg(debug_assertions)]
+        if checkpoint != 0 {
+            core::ptr::write_bytes(checkpoint as *mut u8, 0xAA, pos - checkpoint);
+        }
+    }
+
+    /// Runs `f` and frees everything it allocated if it succeeds.
+    ///
+    /// The result is `Copy` so it can't own heap memory allocated by `f`, and `f` must not grow
+    /// or otherwise reallocate anything allocated before the scope, which is asserted in debug
+    /// builds. Errors may carry heap memory, so nothing is freed when `f` fails.
+    pub fn scoped<T: Copy, E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+        let checkpoint = self.checkpoint();
+        let scope_ptr = self.state(SCOPE_OFFSET);
+        // SAFETY: the state words are at the start of the region handed to `new`.
+        let outer_scope = unsafe { scope_ptr.replace(checkpoint) };
+
+        let result = f();
+
+        unsafe {
+            *scope_ptr = outer_scope;
+            if result.is_ok() {
+                // SAFETY: nothing allocated by `f` outlives it, as documented above.
+                self.rollback(checkpoint);
+            }
         }
+        result
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Allocator over a zeroed region owned by the test.
+    fn allocator(heap: &mut [u64]) -> BumpAllocator {
+        BumpAllocator::new(heap.as_mut_ptr() as usize, heap.len() * 8)
+    }
+
+    fn alloc(allocator: &BumpAllocator, size: usize) -> *mut u8 {
+        unsafe { allocator.alloc(Layout::from_size_align(size, 8).unwrap()) }
+    }
+
+    #[test]
+    fn test_rollback_reuses_memory_and_tracks_peak() {
+        let mut heap = vec![0; 128];
+        let allocator = allocator(&mut heap);
+
+        let outer = alloc(&allocator, 64);
+        assert_eq!(allocator.used(), STATE_LEN + 64);
+
+        let checkpoint = allocator.checkpoint();
+        let inner = alloc(&allocator, 256);
+        unsafe { allocator.rollback(checkpoint) };
+
+        // The rolled back memory is handed out again, the peak is kept.
+        assert_eq!(alloc(&allocator, 32), inner);
+        assert!(outer < inner);
+        assert_eq!(allocator.used(), STATE_LEN + 64 + 256);
+    }
+
+    #[test]
+    fn test_scoped_frees_only_on_success() {
+        let mut heap = vec![0; 128];
+        let allocator = allocator(&mut heap);
+        alloc(&allocator, 8);
+        let checkpoint = allocator.checkpoint();
+
+        let result: Result<(), ()> = allocator.scoped(|| {
+            alloc(&allocator, 128);
+            Ok(())
+        });
+        assert!(result.is_ok());
+        assert_eq!(allocator.checkpoint(), checkpoint);
+
+        let result: Result<(), ()> = allocator.scoped(|| {
+            alloc(&allocator, 128);
+            Err(())
+        });
+        assert!(result.is_err());
+        assert_eq!(allocator.checkpoint(), checkpoint + 128);
+    }
+
+    #[test]
+    fn test_alloc_fails_once_out_of_memory() {
+        let mut heap = vec![0; 16];
+        let allocator = allocator(&mut heap);
+
+        assert!(alloc(&allocator, 128 - STATE_LEN + 8).is_null());
+        assert!(!alloc(&allocator, 128 - STATE_LEN - 8).is_null());
+        assert!(alloc(&allocator, 16).is_null());
+        assert!(!alloc(&allocator, 8).is_null());
+    }
+
+    #[test]
+    #[should_panic(expected = "reallocated inside it")]
+    fn test_scoped_asserts_nothing_escapes() {
+        let mut heap = vec![0; 128];
+        let allocator = allocator(&mut heap);
+        let layout = Layout::from_size_align(8, 8).unwrap();
+        let outer = alloc(&allocator, 8);
+
+        let _: Result<(), ()> = allocator.scoped(|| {
+            unsafe { allocator.realloc(outer, layout, 64) };
+            Ok(())
+        });
+    }
+}
@@ -1,5 +1,8 @@
 pub const SEED_PREFIX: &[u8] = b"super_txn";
 pub const SEED_TRANSACTION: &[u8] = b"transaction";
+pub const SEED_BATCH: &[u8] = b"batch";
 pub const SEED_BATCH_TRANSACTION: &[u8] = b"batch_transaction";
+pub const SEED_CREATOR_AUTHORITY: &[u8] = b"creator_authority";
+pub const SEED_EXECUTION_POLICY: &[u8] = b"execution_policy";
 pub const SEED_EPHEMERAL_SIGNER: &[u8] = b"ephemeral_signer";
 pub const SEED_TRANSACTION_BUFFER: &[u8] = b"transaction_buffer";
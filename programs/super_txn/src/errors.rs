@@ -86,10 +86,46 @@ pub enum SuperTxnError {
     InvalidInstructionArgs,
     #[msg("Final message buffer hash doesnt match the expected hash")]
     FinalBufferHashMismatch,
-    #[msg("Final buffer size cannot exceed 4000 bytes")]
+    #[msg("Final buffer size exceeds the maximum allowed")]
     FinalBufferSizeExceeded,
     #[msg("Final buffer size mismatch")]
     FinalBufferSizeMismatch,
     #[msg("multisig_create has been deprecated. Use multisig_create_v2 instead.")]
     MultisigCreateDeprecated,
+    #[msg("Buffer writes must start on a chunk boundary and cover whole chunks")]
+    UnalignedBufferWrite,
+    #[msg("Not every chunk of the transaction buffer has been written")]
+    BufferChunksIncomplete,
+    #[msg("Buffer chunk doesn't match the committed chunk Merkle root")]
+    ChunkProofMismatch,
+    #[msg("Account is too small, grow it with a realloc instruction first")]
+    AccountNotLargeEnough,
+    #[msg("SuperTransaction exceeds the maximum account size")]
+    TransactionTooLarge,
+    #[msg("SuperTransaction message has already been written")]
+    TransactionNotPending,
+    #[msg("Memo exceeds the maximum allowed length")]
+    MemoTooLong,
+    #[msg("Batch cannot hold any more transactions")]
+    BatchFull,
+    #[msg("Transaction has no approval policy")]
+    ApprovalPolicyMissing,
+    #[msg("SuperTransaction lamports don't cover both the bounty and the rent exemption")]
+    BountyNotCovered,
+    #[msg("SuperTransaction is not valid yet")]
+    TransactionNotYetValid,
+    #[msg("SuperTransaction has expired")]
+    TransactionExpired,
+    #[msg("SuperTransaction has not expired")]
+    TransactionNotExpired,
+    #[msg("Post-execution assertion failed")]
+    AssertionFailed,
+    #[msg("Instruction calls a program not allowed by the execution policy")]
+    ProgramNotAllowed,
+    #[msg("SuperTransaction is pending, complete it with super_transaction_realloc")]
+    TransactionPending,
+    #[msg("SuperTransaction belongs to a batch, execute or close it through the batch")]
+    TransactionInBatch,
+    #[msg("Batch transactions passed out of order or not all of them")]
+    InvalidBatchTransactions,
 }
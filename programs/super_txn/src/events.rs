@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ApprovalStatus, Vote};
+
+/// Emitted when a `TransactionBuffer` is created.
+#[event]
+pub struct BufferCreated {
+    pub transaction_buffer: Pubkey,
+    pub creator: Pubkey,
+    pub buffer_index: u8,
+    pub final_buffer_hash: [u8; 32],
+    pub final_buffer_size: u32,
+}
+
+/// Emitted when a slice is written to a `TransactionBuffer`, either appended or at an offset.
+#[event]
+pub struct BufferExtended {
+    pub transaction_buffer: Pubkey,
+    pub creator: Pubkey,
+    pub buffer_index: u8,
+    /// Offset of the written slice in the final buffer.
+    pub offset: u32,
+    /// Length of the written slice.
+    pub length: u32,
+}
+
+/// Emitted when a `TransactionBuffer` is closed, either explicitly or after
+/// a `SuperTransaction` has been created from it.
+#[event]
+pub struct BufferClosed {
+    pub transaction_buffer: Pubkey,
+    pub creator: Pubkey,
+    pub buffer_index: u8,
+}
+
+/// Emitted when a `SuperTransaction` is created.
+#[event]
+pub struct SuperTransactionCreated {
+    pub transaction: Pubkey,
+    pub creator: Pubkey,
+    pub transaction_index: u8,
+    /// Hash of the serialized `TransactionMessage`.
+    pub message_hash: [u8; 32],
+    pub memo: Option<String>,
+}
+
+/// Emitted every time instructions of a `SuperTransaction` are executed.
+#[event]
+pub struct SuperTransactionExecuted {
+    pub transaction: Pubkey,
+    pub creator: Pubkey,
+    pub transaction_index: u8,
+    /// Signer that executed the instructions.
+    pub executor: Pubkey,
+    /// Number of instructions executed by this call.
+    pub instructions_executed: u8,
+    /// Index of the next instruction to execute, equal to the number of
+    /// instructions once the transaction is fully executed.
+    pub next_instruction_index: u8,
+    /// Compute units consumed by the executed instructions.
+    pub compute_units_consumed: u64,
+}
+
+/// Emitted when a member votes on a `SuperTransaction` with an approval policy.
+#[event]
+pub struct SuperTransactionVoted {
+    pub transaction: Pubkey,
+    pub creator: Pubkey,
+    pub transaction_index: u8,
+    pub member: Pubkey,
+    pub vote: Vote,
+    /// Status of the approval policy after the vote.
+    pub status: ApprovalStatus,
+}
+
+/// Emitted before every wrapped instruction is invoked. A failing CPI aborts the
+/// transaction, so the last one logged is the instruction that failed.
+#[event]
+pub struct InstructionInvoked {
+    /// Index of the instruction in the message.
+    pub index: u8,
+    pub program_id: Pubkey,
+}
+
+/// Emitted when a best-effort execution skipped instructions.
+#[event]
+pub struct InstructionsSkipped {
+    pub transaction: Pubkey,
+    /// Bitmap of the skipped instructions, one bit per instruction.
+    pub skipped_instructions: Vec<u8>,
+}
+
+/// Emitted when the escrowed bounty of a `SuperTransaction` is paid to its executor.
+#[event]
+pub struct BountyPaid {
+    pub transaction: Pubkey,
+    pub executor: Pubkey,
+    pub lamports: u64,
+}
+
+/// Emitted when a `SuperTransaction` is closed, either explicitly or after its execution.
+#[event]
+pub struct SuperTransactionClosed {
+    pub transaction: Pubkey,
+    pub creator: Pubkey,
+    pub transaction_index: u8,
+}
@@ -1,11 +1,27 @@
-use anchor_lang::{prelude::*, system_program};
+use allocator::{heap_used, HEAP_SIZE};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::compute_units::sol_remaining_compute_units;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::program::set_return_data;
+use anchor_lang::system_program;
 use constants::*;
 use errors::SuperTxnError;
-use state::{SuperTransaction, TransactionBuffer, TransactionMessage};
-use utils::{close, derive_ephemeral_signers, ExecutableTransactionMessage};
+use events::*;
+use state::{
+    ApprovalPolicy, Assertion, Batch, ExecutionPolicy, Executor, SuperTransaction,
+    SuperTransactionMessage, SuperTransactionMessageRef, TransactionBuffer, TransactionBufferRef,
+    TransactionMessage, TransactionMessageView, ValidityBound, Vote,
+};
+use std::ops::Range;
+use utils::{
+    close, derive_creator_authority, derive_ephemeral_signer_bumps, derive_ephemeral_signers,
+    realloc, ExecutableTransactionMessage, ExecutionMode, ExecutionOutcome,
+};
 pub mod allocator;
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod state;
 pub mod utils;
 
@@ -40,8 +56,13 @@ pub mod super_txn {
     }
 
     /// Close a transaction buffer account.
-    pub fn txn_buffer_close(_ctx: Context<TransactionBufferClose>) -> Result<()> {
-        Ok(())
+    pub fn txn_buffer_close(ctx: Context<TransactionBufferClose>) -> Result<()> {
+        TransactionBufferClose::transaction_buffer_close(ctx)
+    }
+
+    /// Grow a transaction buffer account by up to 10240 bytes towards its final size.
+    pub fn txn_buffer_realloc(ctx: Context<TransactionBufferRealloc>) -> Result<()> {
+        TransactionBufferRealloc::transaction_buffer_realloc(ctx)
     }
 
     /// Extend a transaction buffer account.
@@ -51,6 +72,24 @@ pub mod super_txn {
     ) -> Result<()> {
         TransactionBufferExtend::transaction_buffer_extend(ctx, args)
     }
+
+    /// Write a slice of a transaction buffer account at the given offset.
+    /// Slices can land in any order.
+    pub fn txn_buffer_write_at(
+        ctx: Context<TransactionBufferWriteAt>,
+        args: TransactionBufferWriteAtArgs,
+    ) -> Result<()> {
+        TransactionBufferWriteAt::transaction_buffer_write_at(ctx, args)
+    }
+
+    /// Execute the message of a finalized transaction buffer and close the buffer,
+    /// without creating a super transaction.
+    pub fn txn_buffer_execute(
+        ctx: Context<TransactionBufferExecute>,
+        args: TransactionBufferExecuteArgs,
+    ) -> Result<()> {
+        TransactionBufferExecute::transaction_buffer_execute(ctx, args)
+    }
     /// Create a new super transaction.
     pub fn super_transaction_create(
         ctx: Context<SuperTransactionCreate>,
@@ -68,17 +107,121 @@ pub mod super_txn {
         SuperTransactionCreateFromBuffer::super_transaction_create_from_buffer(ctx, args)
     }
 
+    /// Grow a super transaction created from a buffer too large to be written in one instruction.
+    /// Once the account is large enough the message is written and the buffer is closed.
+    pub fn super_transaction_realloc(ctx: Context<SuperTransactionRealloc>) -> Result<()> {
+        SuperTransactionRealloc::super_transaction_realloc(ctx)
+    }
+
     /// Execute a super transaction.
-    /// The transaction must be `Approved`.
-    pub fn super_transaction_execute(ctx: Context<SuperTransactionExecute>) -> Result<()> {
-        SuperTransactionExecute::super_transaction_execute(ctx)
+    /// Transactions capturing return data are kept open until the creator closes them,
+    /// the last captured return data is set as the return data of this instruction.
+    /// If the transaction has an approval policy it must be `Approved` and its time lock released.
+    /// The executor must be allowed by the transaction `executor`, the rent is refunded to the creator.
+    /// The escrowed bounty is paid to the executor once every instruction has been executed.
+    /// The current slot and timestamp must be within the transaction validity window.
+    /// The transaction assertions are checked after the last instruction.
+    /// Every called program must be allowed by the creator execution policy.
+    pub fn super_transaction_execute(
+        ctx: Context<SuperTransactionExecute>,
+        args: SuperTransactionExecuteArgs,
+    ) -> Result<()> {
+        SuperTransactionExecute::super_transaction_execute(ctx, args)
+    }
+
+    /// Execute up to `max_instructions` instructions of a super transaction and save the progress.
+    /// The transaction is closed once its last instruction has been executed.
+    pub fn super_transaction_execute_range(
+        ctx: Context<SuperTransactionExecute>,
+        args: SuperTransactionExecuteRangeArgs,
+    ) -> Result<()> {
+        SuperTransactionExecute::super_transaction_execute_range(ctx, args)
+    }
+
+    /// Execute the remaining instructions of a super transaction, skipping the ones that
+    /// reference missing accounts or a non-executable program instead of failing.
+    /// The skipped instructions are reported in the `InstructionsSkipped` event.
+    pub fn super_transaction_execute_best_effort(
+        ctx: Context<SuperTransactionExecute>,
+        args: SuperTransactionExecuteBestEffortArgs,
+    ) -> Result<()> {
+        SuperTransactionExecute::super_transaction_execute_best_effort(ctx, args)
+    }
+
+    /// Approve a super transaction on behalf of an approval policy member.
+    pub fn super_transaction_approve(ctx: Context<SuperTransactionVote>) -> Result<()> {
+        SuperTransactionVote::super_transaction_vote(ctx, Vote::Approve)
+    }
+
+    /// Reject a super transaction on behalf of an approval policy member.
+    pub fn super_transaction_reject(ctx: Context<SuperTransactionVote>) -> Result<()> {
+        SuperTransactionVote::super_transaction_vote(ctx, Vote::Reject)
+    }
+
+    /// Cancel an approved super transaction on behalf of an approval policy member.
+    pub fn super_transaction_cancel(ctx: Context<SuperTransactionVote>) -> Result<()> {
+        SuperTransactionVote::super_transaction_vote(ctx, Vote::Cancel)
+    }
+
+    /// Create the execution policy restricting the programs the creator transactions can call.
+    pub fn execution_policy_create(
+        ctx: Context<ExecutionPolicyCreate>,
+        args: ExecutionPolicyArgs,
+    ) -> Result<()> {
+        ExecutionPolicyCreate::execution_policy_create(ctx, args)
+    }
+
+    /// Replace the program lists of an execution policy.
+    pub fn execution_policy_update(
+        ctx: Context<ExecutionPolicyUpdate>,
+        args: ExecutionPolicyArgs,
+    ) -> Result<()> {
+        ExecutionPolicyUpdate::execution_policy_update(ctx, args)
+    }
+
+    /// Close an execution policy, transactions can then call any program but super_txn.
+    pub fn execution_policy_close(ctx: Context<ExecutionPolicyClose>) -> Result<()> {
+        ExecutionPolicyClose::execution_policy_close(ctx)
+    }
+
+    /// Create a batch of super transactions that are executed in order.
+    pub fn batch_create(ctx: Context<BatchCreate>, args: BatchCreateArgs) -> Result<()> {
+        BatchCreate::batch_create(ctx, args)
+    }
+
+    /// Add a super transaction to the end of a batch.
+    pub fn batch_add_transaction(
+        ctx: Context<BatchAddTransaction>,
+        args: BatchAddTransactionArgs,
+    ) -> Result<()> {
+        BatchAddTransaction::batch_add_transaction(ctx, args)
+    }
+
+    /// Execute the next super transaction of a batch.
+    /// The batch is closed once all of its transactions have been executed.
+    pub fn batch_execute_next(ctx: Context<BatchExecuteNext>) -> Result<()> {
+        BatchExecuteNext::batch_execute_next(ctx)
+    }
+
+    /// Close a batch and its transactions that haven't been executed, in order.
+    /// The rent is refunded to the batch rent payer.
+    pub fn batch_close<'info>(ctx: Context<'_, '_, 'info, 'info, BatchClose<'info>>) -> Result<()> {
+        BatchClose::batch_close(ctx)
     }
 
     /// Closes a `SuperTransaction`
     pub fn super_transaction_accounts_close(
-        _ctx: Context<SuperTransactionAccountsClose>,
+        ctx: Context<SuperTransactionAccountsClose>,
     ) -> Result<()> {
-        Ok(())
+        SuperTransactionAccountsClose::super_transaction_accounts_close(ctx)
+    }
+
+    /// Closes an expired `SuperTransaction`, can be called by anyone.
+    /// The rent is refunded to the creator.
+    pub fn super_transaction_close_expired(
+        ctx: Context<SuperTransactionCloseExpired>,
+    ) -> Result<()> {
+        SuperTransactionCloseExpired::super_transaction_close_expired(ctx)
     }
 }
 
@@ -89,9 +232,12 @@ pub struct TransactionBufferCreateArgs {
     /// Hash of the final assembled transaction message.
     pub final_buffer_hash: [u8; 32],
     /// Final size of the buffer.
-    pub final_buffer_size: u16,
+    pub final_buffer_size: u32,
     /// Initial slice of the buffer.
     pub buffer: Vec<u8>,
+    /// Optional Merkle root over the chunks of the final buffer.
+    /// When set, the initial slice must be empty and every chunk is uploaded with a proof.
+    pub chunk_merkle_root: Option<[u8; 32]>,
 }
 
 #[derive(Accounts)]
@@ -100,7 +246,7 @@ pub struct TransactionBufferCreate<'info> {
     #[account(
         init,
         payer = rent_payer,
-        space = TransactionBuffer::size(args.final_buffer_size)?,
+        space = TransactionBuffer::initial_size(args.final_buffer_size)?,
         seeds = [
             SEED_PREFIX,
             SEED_TRANSACTION_BUFFER,
@@ -136,14 +282,23 @@ impl TransactionBufferCreate<'_> {
         // Get the buffer index.
         let buffer_index = args.buffer_index;
 
-        transaction_buffer.creator = creator.key();
-        transaction_buffer.buffer_index = buffer_index;
-        transaction_buffer.final_buffer_hash = args.final_buffer_hash;
-        transaction_buffer.final_buffer_size = args.final_buffer_size;
-        transaction_buffer.buffer = args.buffer;
+        // Initialize the buffer with its first slice
+        **transaction_buffer = TransactionBuffer::new(
+            creator.key(),
+            buffer_index,
+            args.final_buffer_hash,
+            args.final_buffer_size,
+            args.chunk_merkle_root,
+            &args.buffer,
+        )?;
 
-        // Invariant function on the transaction buffer
-        transaction_buffer.invariant()?;
+        emit!(BufferCreated {
+            transaction_buffer: transaction_buffer.key(),
+            creator: creator.key(),
+            buffer_index,
+            final_buffer_hash: args.final_buffer_hash,
+            final_buffer_size: args.final_buffer_size,
+        });
 
         Ok(())
     }
@@ -151,32 +306,116 @@ impl TransactionBufferCreate<'_> {
 
 #[derive(Accounts)]
 pub struct TransactionBufferClose<'info> {
+    /// CHECK: only the header is read below, large buffers don't fit on the heap.
     #[account(
         mut,
-        // Rent gets returned to the creator
-        close = creator,
+        // Buffers are only created at their PDA, so checking the owner and the
+        // creator in the header stands in for the seeds constraint
+        owner = crate::ID @ SuperTxnError::IllegalAccountOwner,
+    )]
+    pub transaction_buffer: AccountInfo<'info>,
+
+    /// The member of the multisig that created the TransactionBuffer.
+    /// Rent gets returned to the creator.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+impl TransactionBufferClose<'_> {
+    /// Close the transaction buffer.
+    /// Account can be closed anytime by the creator, regardless of the
+    /// current multisig transaction index.
+    pub fn transaction_buffer_close(ctx: Context<Self>) -> Result<()> {
+        let transaction_buffer_account_info = &ctx.accounts.transaction_buffer;
+        let transaction_buffer = {
+            let txn_buffer_account_data = transaction_buffer_account_info.try_borrow_data()?;
+            TransactionBufferRef::load(&**txn_buffer_account_data)?.header
+        };
+
         // Only the creator can close the buffer
-        constraint = transaction_buffer.creator == creator.key() @ SuperTxnError::Unauthorized,
-        // Account can be closed anytime by the creator, regardless of the
-        // current multisig transaction index
-        seeds = [
-            SEED_PREFIX,
-            SEED_TRANSACTION_BUFFER,
-            creator.key().as_ref(),
-            &transaction_buffer.buffer_index.to_le_bytes()
-        ],
-        bump
+        require_keys_eq!(
+            transaction_buffer.creator,
+            ctx.accounts.creator.key(),
+            SuperTxnError::Unauthorized
+        );
+
+        close(
+            transaction_buffer_account_info.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+        )?;
+
+        emit!(BufferClosed {
+            transaction_buffer: transaction_buffer_account_info.key(),
+            creator: ctx.accounts.creator.key(),
+            buffer_index: transaction_buffer.buffer_index,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct TransactionBufferRealloc<'info> {
+    /// CHECK: only the header is read below, large buffers don't fit on the heap.
+    #[account(
+        mut,
+        // Buffers are only created at their PDA, so checking the owner and the
+        // creator in the header stands in for the seeds constraint
+        owner = crate::ID @ SuperTxnError::IllegalAccountOwner,
     )]
-    pub transaction_buffer: Account<'info, TransactionBuffer>,
+    pub transaction_buffer: AccountInfo<'info>,
 
     /// The member of the multisig that created the TransactionBuffer.
     pub creator: Signer<'info>,
+
+    /// The payer for the additional rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl TransactionBufferRealloc<'_> {
+    /// Grow the transaction buffer by one realloc step.
+    pub fn transaction_buffer_realloc(ctx: Context<Self>) -> Result<()> {
+        let transaction_buffer_account_info = &ctx.accounts.transaction_buffer;
+        let transaction_buffer = {
+            let txn_buffer_account_data = transaction_buffer_account_info.try_borrow_data()?;
+            TransactionBufferRef::load(&**txn_buffer_account_data)?.header
+        };
+
+        // Only the creator can grow the buffer
+        require_keys_eq!(
+            transaction_buffer.creator,
+            ctx.accounts.creator.key(),
+            SuperTxnError::Unauthorized
+        );
+
+        let new_len = transaction_buffer.next_size(transaction_buffer_account_info.data_len())?;
+        realloc(
+            transaction_buffer_account_info,
+            new_len,
+            0,
+            &ctx.accounts.rent_payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        msg!(
+            "Transaction buffer is {} of {} bytes",
+            new_len,
+            TransactionBuffer::size(transaction_buffer.final_buffer_size)?
+        );
+        Ok(())
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct TransactionBufferExtendArgs {
     // Buffer to extend the TransactionBuffer with.
     pub buffer: Vec<u8>,
+    // Merkle proof of the appended chunk, required if the buffer has a `chunk_merkle_root`.
+    pub chunk_proof: Option<Vec<[u8; 32]>>,
+    // Fail with `MissingAccount` instead of silently succeeding if the buffer doesn't exist.
+    pub strict: bool,
 }
 #[derive(Accounts)]
 #[instruction(args: TransactionBufferExtendArgs)]
@@ -190,23 +429,23 @@ pub struct TransactionBufferExtend<'info> {
 impl TransactionBufferExtend<'_> {
     fn validate(&self, args: &TransactionBufferExtendArgs) -> Result<()> {
         let txn_buffer_account_data = self.transaction_buffer.try_borrow_data()?;
-        let transaction_buffer =
-            TransactionBuffer::try_deserialize(&mut txn_buffer_account_data.as_ref())?;
-        let current_buffer_size = transaction_buffer.buffer.len() as u16;
+        let transaction_buffer = TransactionBufferRef::load(&**txn_buffer_account_data)?;
+        let current_buffer_size = transaction_buffer.buffer().len() as u32;
         let remaining_space = transaction_buffer
+            .header
             .final_buffer_size
             .checked_sub(current_buffer_size)
             .unwrap();
 
         // Check if the new data exceeds the remaining space
-        let new_data_size = args.buffer.len() as u16;
+        let new_data_size = args.buffer.len() as u32;
         require!(
             new_data_size <= remaining_space,
             SuperTxnError::FinalBufferSizeExceeded
         );
 
         require_eq!(
-            transaction_buffer.creator,
+            transaction_buffer.header.creator,
             self.creator.key(),
             SuperTxnError::Unauthorized
         );
@@ -219,29 +458,197 @@ impl TransactionBufferExtend<'_> {
     ) -> Result<()> {
         let transaction_buffer_account_info = &ctx.accounts.transaction_buffer;
         if transaction_buffer_account_info.data_is_empty() {
+            require!(!args.strict, SuperTxnError::MissingAccount);
             msg!("Warning: Transaction buffer is empty, fail silently for jito bundles");
             return Ok(());
         }
-        // if below code fails, then txn is not clean.
-
-        // Log any validation issues but continue
-        let _ = ctx.accounts.validate(&args);
+        // Only a missing buffer fails silently, any other issue is returned
+        ctx.accounts.validate(&args)?;
 
-        // Get mutable reference to account data
+        // The bytes are appended in place, the buffer isn't copied out of the account data
         let mut txn_buffer_account_data = transaction_buffer_account_info.try_borrow_mut_data()?;
-        let mut transaction_buffer =
-            TransactionBuffer::try_deserialize(&mut txn_buffer_account_data.as_ref())?;
+        let mut transaction_buffer = TransactionBufferRef::load(&mut **txn_buffer_account_data)?;
         let buffer_slice_extension = args.buffer;
+        let offset = transaction_buffer.buffer().len() as u32;
+
+        // Large buffers have to be grown with `txn_buffer_realloc` before they can be extended
+        transaction_buffer.extend(&buffer_slice_extension, args.chunk_proof.as_deref())?;
+
+        emit!(BufferExtended {
+            transaction_buffer: transaction_buffer_account_info.key(),
+            creator: transaction_buffer.header.creator,
+            buffer_index: transaction_buffer.header.buffer_index,
+            offset,
+            length: buffer_slice_extension.len() as u32,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransactionBufferWriteAtArgs {
+    /// Offset into the final buffer, must be a multiple of `TRANSACTION_BUFFER_CHUNK_SIZE`.
+    pub offset: u32,
+    /// Bytes to write at `offset`.
+    pub bytes: Vec<u8>,
+    /// Merkle proof of the written chunk, required if the buffer has a `chunk_merkle_root`.
+    pub chunk_proof: Option<Vec<[u8; 32]>>,
+    /// Fail with `MissingAccount` instead of silently succeeding if the buffer doesn't exist.
+    pub strict: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args: TransactionBufferWriteAtArgs)]
+pub struct TransactionBufferWriteAt<'info> {
+    /// CHECK: checked below but silently fails
+    #[account(mut)]
+    pub transaction_buffer: AccountInfo<'info>,
+    pub creator: Signer<'info>,
+}
+
+impl TransactionBufferWriteAt<'_> {
+    pub fn transaction_buffer_write_at(
+        ctx: Context<Self>,
+        args: TransactionBufferWriteAtArgs,
+    ) -> Result<()> {
+        let transaction_buffer_account_info = &ctx.accounts.transaction_buffer;
+        if transaction_buffer_account_info.data_is_empty() {
+            require!(!args.strict, SuperTxnError::MissingAccount);
+            msg!("Warning: Transaction buffer is empty, fail silently for jito bundles");
+            return Ok(());
+        }
+
+        let mut txn_buffer_account_data = transaction_buffer_account_info.try_borrow_mut_data()?;
+        let mut transaction_buffer = TransactionBufferRef::load(&mut **txn_buffer_account_data)?;
 
-        // Extend the buffer, log if it panics
-        transaction_buffer
-            .buffer
-            .extend_from_slice(&buffer_slice_extension);
+        require_eq!(
+            transaction_buffer.header.creator,
+            ctx.accounts.creator.key(),
+            SuperTxnError::Unauthorized
+        );
+
+        // Preallocates the buffer to `final_buffer_size` on the first write, so the
+        // account must be fully grown, and marks the chunks covered by `args.bytes` as written.
+        transaction_buffer.write_at(
+            args.offset as usize,
+            &args.bytes,
+            args.chunk_proof.as_deref(),
+        )?;
+
+        emit!(BufferExtended {
+            transaction_buffer: transaction_buffer_account_info.key(),
+            creator: transaction_buffer.header.creator,
+            buffer_index: transaction_buffer.header.buffer_index,
+            offset: args.offset,
+            length: args.bytes.len() as u32,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransactionBufferExecuteArgs {
+    /// Number of ephemeral signing PDAs required by the message, derived from the buffer key.
+    pub ephemeral_signers: u8,
+    /// Fail with `MissingAccount` instead of silently succeeding if the buffer doesn't exist.
+    pub strict: bool,
+}
+
+#[derive(Accounts)]
+pub struct TransactionBufferExecute<'info> {
+    /// CHECK: checked below but silently fails
+    #[account(mut)]
+    pub transaction_buffer: AccountInfo<'info>,
+
+    /// The creator of the buffer, receives the rent refund.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The execution policy of the creator, applied if it has been created.
+    /// CHECK: address checked by the seeds constraint, deserialized below.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_EXECUTION_POLICY, creator.key().as_ref()],
+        bump
+    )]
+    pub execution_policy: AccountInfo<'info>,
+    // `remaining_accounts` must be laid out as documented on `SuperTransactionExecute`.
+}
+
+impl TransactionBufferExecute<'_> {
+    /// Execute the finalized buffer message and close the buffer.
+    pub fn transaction_buffer_execute(
+        ctx: Context<Self>,
+        args: TransactionBufferExecuteArgs,
+    ) -> Result<()> {
+        let transaction_buffer_account_info = &ctx.accounts.transaction_buffer;
+        if transaction_buffer_account_info.data_is_empty() {
+            require!(!args.strict, SuperTxnError::MissingAccount);
+            msg!("Warning: Transaction buffer is empty, fail silently for jito bundles");
+            return Ok(());
+        }
+        // The program signs for the creator authority, the buffer can't be forged.
+        require_keys_eq!(
+            *transaction_buffer_account_info.owner,
+            crate::ID,
+            SuperTxnError::IllegalAccountOwner
+        );
+        // The message is read in place from the account data, which stays borrowed while the
+        // CPIs are executed: the buffer can't be passed writable to them, see `check_not_protected`.
+        let txn_buffer_account_data = transaction_buffer_account_info.try_borrow_data()?;
+        let transaction_buffer = TransactionBufferRef::load(&**txn_buffer_account_data)?;
+        require_eq!(
+            transaction_buffer.header.creator,
+            ctx.accounts.creator.key(),
+            SuperTxnError::Unauthorized
+        );
+
+        // Validate that the buffer is complete and matches the final hash and size
+        transaction_buffer.validate_finalized()?;
 
-        transaction_buffer.invariant()?;
+        let transaction_message =
+            SuperTransactionMessageRef::from_transaction_message(transaction_buffer.buffer())?;
+        let transaction_buffer_key = transaction_buffer_account_info.key();
+
+        // The buffer stands in for the super transaction, ephemeral signers are derived from its key.
+        let transaction = SuperTransaction {
+            creator: transaction_buffer.header.creator,
+            index: transaction_buffer.header.buffer_index,
+            ephemeral_signer_bumps: derive_ephemeral_signer_bumps(
+                &transaction_buffer_key,
+                args.ephemeral_signers,
+            ),
+            ..Default::default()
+        };
+        let num_instructions = transaction_message.num_instructions();
+
+        let execution_policy =
+            ExecutionPolicy::load(&ctx.accounts.execution_policy)?.unwrap_or_default();
+
+        SuperTransactionExecute::execute_instructions(
+            transaction_buffer_key,
+            &transaction,
+            &transaction_message,
+            ctx.accounts.creator.key(),
+            &execution_policy,
+            ctx.remaining_accounts,
+            0..num_instructions,
+            ExecutionMode::default(),
+        )?;
+
+        let transaction_buffer = transaction_buffer.header;
+        drop(txn_buffer_account_data);
+        close(
+            transaction_buffer_account_info.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+        )?;
 
-        // Serialize the modified transaction buffer back to account data
-        transaction_buffer.try_serialize(&mut *txn_buffer_account_data)?;
+        emit!(BufferClosed {
+            transaction_buffer: transaction_buffer_key,
+            creator: transaction_buffer.creator,
+            buffer_index: transaction_buffer.buffer_index,
+        });
 
         Ok(())
     }
@@ -255,6 +662,33 @@ pub struct SuperTransactionCreateArgs {
     pub ephemeral_signers: u8,
     pub transaction_message: Vec<u8>,
     pub memo: Option<String>,
+    /// Optional approval policy the transaction has to satisfy before it can be executed.
+    pub approval: Option<ApprovalPolicyArgs>,
+    /// Who is allowed to execute the transaction.
+    pub executor: Executor,
+    /// Lamports escrowed from the rent payer and paid to the executor after execution.
+    pub bounty_lamports: u64,
+    /// The transaction can't be executed before this slot or timestamp.
+    pub valid_after: Option<ValidityBound>,
+    /// The transaction can't be executed after this slot or timestamp.
+    pub valid_until: Option<ValidityBound>,
+    /// Checks on the message accounts once every instruction has been executed.
+    pub assertions: Vec<Assertion>,
+    /// Capture the return data of the executed instructions into the transaction account.
+    pub capture_return_data: bool,
+    /// Fail with `MissingAccount` instead of silently succeeding if the transaction buffer
+    /// passed to `super_transaction_create_from_buffer` doesn't exist.
+    pub strict: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ApprovalPolicyArgs {
+    /// Members allowed to approve, reject or cancel the transaction.
+    pub members: Vec<Pubkey>,
+    /// Number of member approvals required before execution.
+    pub threshold: u16,
+    /// Seconds to wait between approval and execution.
+    pub time_lock: u32,
 }
 
 #[derive(Accounts)]
@@ -263,7 +697,13 @@ pub struct SuperTransactionCreate<'info> {
     #[account(
         init,
         payer = rent_payer,
-        space = SuperTransaction::size(args.ephemeral_signers, &args.transaction_message)?,
+        space = SuperTransaction::size(
+            args.ephemeral_signers,
+            &args.transaction_message,
+            args.memo.as_deref(),
+            args.approval.as_ref().map(|approval| approval.members.len()),
+            &args.assertions,
+        )?,
         seeds = [
             SEED_PREFIX,
             SEED_TRANSACTION,
@@ -290,34 +730,81 @@ impl<'info> SuperTransactionCreate<'info> {
         ctx: Context<Self>,
         args: SuperTransactionCreateArgs,
     ) -> Result<()> {
-        let transaction = &mut ctx.accounts.transaction;
-        let creator = &mut ctx.accounts.creator;
+        let event = SuperTransactionCreated {
+            transaction: ctx.accounts.transaction.key(),
+            creator: ctx.accounts.creator.key(),
+            transaction_index: args.transaction_index,
+            message_hash: hash(&args.transaction_message).to_bytes(),
+            memo: args.memo.clone(),
+        };
 
         let transaction_message =
-            TransactionMessage::deserialize(&mut args.transaction_message.as_slice())?;
+            TransactionMessage::deserialize(&mut args.transaction_message.as_slice())?
+                .try_into()?;
+        Self::initialize(ctx, args, transaction_message, None)?;
+
+        emit!(event);
+
+        Ok(())
+    }
+
+    /// Initialize the super transaction fields from `args` with `transaction_message`.
+    /// `pending_message_hash` is set if the message is written later by `super_transaction_realloc`.
+    pub fn initialize(
+        ctx: Context<Self>,
+        args: SuperTransactionCreateArgs,
+        transaction_message: SuperTransactionMessage,
+        pending_message_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let transaction = &mut ctx.accounts.transaction;
+        let creator = &mut ctx.accounts.creator;
 
         let transaction_key = transaction.key();
 
-        let ephemeral_signer_bumps: Vec<u8> = (0..args.ephemeral_signers)
-            .map(|ephemeral_signer_index| {
-                let ephemeral_signer_seeds = &[
-                    SEED_PREFIX,
-                    transaction_key.as_ref(),
-                    SEED_EPHEMERAL_SIGNER,
-                    &ephemeral_signer_index.to_le_bytes(),
-                ];
-
-                let (_, bump) =
-                    Pubkey::find_program_address(ephemeral_signer_seeds, ctx.program_id);
-                bump
-            })
-            .collect();
+        let ephemeral_signer_bumps =
+            derive_ephemeral_signer_bumps(&transaction_key, args.ephemeral_signers);
+
+        let clock = Clock::get()?;
 
         // Initialize the transaction fields.
         transaction.creator = creator.key();
-        // transaction.index = transaction_index;
+        transaction.index = args.transaction_index;
+        transaction.bump = ctx.bumps.transaction;
+        transaction.created_at_slot = clock.slot;
+        transaction.created_at_unix = clock.unix_timestamp;
+        transaction.memo = args.memo;
+        transaction.approval = args
+            .approval
+            .map(|approval| {
+                ApprovalPolicy::new(approval.members, approval.threshold, approval.time_lock)
+            })
+            .transpose()?;
+        transaction.executor = args.executor;
+        transaction.bounty_lamports = args.bounty_lamports;
+        transaction.valid_after = args.valid_after;
+        transaction.valid_until = args.valid_until;
+        transaction.assertions = args.assertions;
+        transaction.capture_return_data = args.capture_return_data;
         transaction.ephemeral_signer_bumps = ephemeral_signer_bumps;
-        transaction.message = transaction_message.try_into()?;
+        transaction.pending_message_hash = pending_message_hash;
+        transaction.set_message(transaction_message)?;
+
+        // Escrow the bounty on top of the rent of the transaction account.
+        if args.bounty_lamports > 0 {
+            let transfer_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.rent_payer.to_account_info(),
+                    to: transaction.to_account_info(),
+                },
+            );
+            system_program::transfer(transfer_context, args.bounty_lamports)?;
+        }
+        let transaction_account_info = transaction.to_account_info();
+        transaction.check_bounty_escrow(
+            transaction_account_info.lamports(),
+            transaction_account_info.data_len(),
+        )?;
 
         Ok(())
     }
@@ -342,10 +829,12 @@ pub struct SuperTransactionCreateFromBuffer<'info> {
 }
 
 impl<'info> SuperTransactionCreateFromBuffer<'info> {
-    pub fn validate(&self, args: &SuperTransactionCreateArgs) -> Result<TransactionBuffer> {
-        let txn_buffer_account_data = self.transaction_buffer.try_borrow_data()?;
-        let transaction_buffer =
-            TransactionBuffer::try_deserialize(&mut txn_buffer_account_data.as_ref())?;
+    pub fn validate<'a>(
+        &self,
+        args: &SuperTransactionCreateArgs,
+        txn_buffer_account_data: &'a [u8],
+    ) -> Result<TransactionBufferRef<&'a [u8]>> {
+        let transaction_buffer = TransactionBufferRef::load(txn_buffer_account_data)?;
 
         // Check that the transaction message is "empty"
         require!(
@@ -353,13 +842,10 @@ impl<'info> SuperTransactionCreateFromBuffer<'info> {
             SuperTxnError::InvalidInstructionArgs
         );
 
-        // Validate that the final hash matches the buffer
-        transaction_buffer.validate_hash()?;
-
-        // Validate that the final size is correct
-        transaction_buffer.validate_size()?;
+        // Validate that the buffer is complete and matches the final hash and size
+        transaction_buffer.validate_finalized()?;
         require_eq!(
-            transaction_buffer.creator,
+            transaction_buffer.header.creator,
             self.creator.key(),
             SuperTxnError::Unauthorized
         );
@@ -372,6 +858,7 @@ impl<'info> SuperTransactionCreateFromBuffer<'info> {
     ) -> Result<()> {
         let transaction_buffer_account_info = &ctx.accounts.transaction_buffer;
         if transaction_buffer_account_info.data_is_empty() {
+            require!(!args.strict, SuperTxnError::MissingAccount);
             msg!("Warning: Transaction buffer is empty, fail silently for jito bundles");
             return Ok(());
         }
@@ -393,40 +880,57 @@ impl<'info> SuperTransactionCreateFromBuffer<'info> {
             .system_program
             .to_account_info();
 
-        // Read-only accounts
-        let transaction_buffer = ctx.accounts.validate(&args)?;
+        // Read-only accounts, the buffer is read in place from the account data
+        let transaction_buffer_account_info = ctx.accounts.transaction_buffer.to_account_info();
+        let txn_buffer_account_data = transaction_buffer_account_info.try_borrow_data()?;
+        let transaction_buffer = ctx.accounts.validate(&args, &txn_buffer_account_data)?;
 
         // Calculate the new required length of the super transaction account,
         // since it was initialized with an empty transaction message
-        let new_len =
-            SuperTransaction::size(args.ephemeral_signers, transaction_buffer.buffer.as_slice())?;
-        // Calculate the rent exemption for new length
-        let rent_exempt_lamports = Rent::get().unwrap().minimum_balance(new_len).max(1);
-
-        // Check the difference between the rent exemption and the current lamports
-        let top_up_lamports =
-            rent_exempt_lamports.saturating_sub(super_transaction_account_info.lamports());
-
-        // System Transfer the remaining difference to the super transaction account
-        let transfer_context = CpiContext::new(
-            system_program.to_account_info(),
-            system_program::Transfer {
-                from: rent_payer_account_info.clone(),
-                to: super_transaction_account_info.clone(),
-            },
-        );
-        system_program::transfer(transfer_context, top_up_lamports)?;
-        // Reallocate the super transaction account to the new length of the
-        // actual transaction message
-        AccountInfo::realloc(&super_transaction_account_info, new_len, true)?;
-
-        // Create the args for the super transaction create instruction
-        let create_args = SuperTransactionCreateArgs {
-            ephemeral_signers: args.ephemeral_signers,
-            transaction_message: transaction_buffer.buffer.clone(),
+        let new_len = SuperTransaction::size(
+            args.ephemeral_signers,
+            transaction_buffer.buffer(),
+            args.memo.as_deref(),
+            args.approval
+                .as_ref()
+                .map(|approval| approval.members.len()),
+            &args.assertions,
+        )?;
+        // The account was created in this instruction, so it can only be grown
+        // up to MAX_PERMITTED_DATA_INCREASE here. Larger transactions are left
+        // pending and completed with `super_transaction_realloc`.
+        let step_len = new_len.min(MAX_PERMITTED_DATA_INCREASE);
+        let is_pending = step_len < new_len;
+
+        // Top up the rent and reallocate the super transaction account to the
+        // new length of the actual transaction message
+        realloc(
+            super_transaction_account_info,
+            step_len,
+            0,
+            rent_payer_account_info,
+            system_program,
+        )?;
+
+        let event = SuperTransactionCreated {
+            transaction: super_transaction_account_info.key(),
+            creator: ctx.accounts.creator.key(),
             transaction_index: args.transaction_index,
-            memo: args.memo,
+            // The buffer hash is validated to be the hash of the final message.
+            message_hash: transaction_buffer.header.final_buffer_hash,
+            memo: args.memo.clone(),
+        };
+
+        // Pending transactions get their message from `super_transaction_realloc`, which writes
+        // it in place. Messages completed here fit in MAX_PERMITTED_DATA_INCREASE, so they are
+        // small enough to be deserialized on the heap.
+        let transaction_message = if is_pending {
+            SuperTransactionMessage::default()
+        } else {
+            TransactionMessage::deserialize(&mut transaction_buffer.buffer())?.try_into()?
         };
+        let transaction_buffer = transaction_buffer.header;
+        drop(txn_buffer_account_data);
 
         // Create the context for the super transaction create instruction
         let context = Context::new(
@@ -436,107 +940,1098 @@ impl<'info> SuperTransactionCreateFromBuffer<'info> {
             ctx.bumps.super_transaction_create,
         );
 
-        // Call the super transaction create instruction
-        SuperTransactionCreate::super_transaction_create(context, create_args)?;
-
-        close(
-            ctx.accounts.transaction_buffer.to_account_info(),
-            ctx.accounts.creator.to_account_info(),
+        // Initialize the super transaction like the super transaction create instruction does
+        SuperTransactionCreate::initialize(
+            context,
+            args,
+            transaction_message,
+            is_pending.then_some(transaction_buffer.final_buffer_hash),
         )?;
 
-        Ok(())
-    }
-}
-
-#[derive(Accounts)]
-pub struct SuperTransactionExecute<'info> {
-    /// The transaction to execute.
-    /// CHECK: checked below
-    #[account(mut)]
-    pub transaction: AccountInfo<'info>,
-    #[account(mut)]
-    pub creator: Signer<'info>,
-    // `remaining_accounts` must include the following accounts in the exact order:
-    // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
-    // 2. Accounts in the order they appear in `message.account_keys`.
-    // 3. Accounts in the order they appear in `message.address_table_lookups`.
-}
+        emit!(event);
 
-impl SuperTransactionExecute<'_> {
-    /// Execute the super transaction.
-    pub fn super_transaction_execute(ctx: Context<Self>) -> Result<()> {
-        let transaction_account_info = &ctx.accounts.transaction;
-        if transaction_account_info.data_is_empty() {
-            msg!("Warning: SuperTransaction is empty, fail silently for jito bundles");
+        if is_pending {
+            msg!(
+                "SuperTransaction needs {} bytes, continue with super_transaction_realloc",
+                new_len
+            );
             return Ok(());
         }
-        {
-            let transaction_account_data = transaction_account_info.try_borrow_mut_data()?;
-            let mut transaction =
-                SuperTransaction::try_deserialize(&mut transaction_account_data.as_ref())?;
-            require_eq!(
-                transaction.creator,
-                ctx.accounts.creator.key(),
-                SuperTxnError::Unauthorized
-            );
-
-            // NOTE: After `take()` is called, the SuperTransaction is reduced to
-            // its default empty value, which means it should no longer be referenced or
-            // used after this point to avoid faulty behavior.
-            // Instead only make use of the returned `transaction` value.
-            let transaction = transaction.take();
-
-            let transaction_key = ctx.accounts.transaction.key();
-            let creator = &ctx.accounts.creator;
-            let creator_key = creator.key();
-            let transaction_message = transaction.message;
-            // let num_lookups = transaction_message.address_table_lookups.len();
-
-            let message_account_infos = ctx
-                .remaining_accounts;
-            // let address_lookup_table_account_infos = ctx
-            //     .remaining_accounts
-            //     .get(..num_lookups)
-            //     .ok_or(SuperTxnError::InvalidNumberOfAccounts)?;
-
-            let (ephemeral_signer_keys, ephemeral_signer_seeds) =
-                derive_ephemeral_signers(transaction_key, &transaction.ephemeral_signer_bumps);
-
-            let executable_message = ExecutableTransactionMessage::new_validated(
-                transaction_message,
-                message_account_infos,
-                &creator_key,
-                &ephemeral_signer_keys,
-            )?;
 
-            // Execute the transaction message instructions one-by-one.
-            // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
-            // which in turn calls `take()` on
-            // `self.message.instructions`, therefore after this point no more
-            // references or usages of `self.message` should be made to avoid
-            // faulty behavior.
-            executable_message.execute_message(&ephemeral_signer_seeds)?;
-        }
         close(
-            ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction_buffer.to_account_info(),
             ctx.accounts.creator.to_account_info(),
         )?;
 
+        emit!(BufferClosed {
+            transaction_buffer: ctx.accounts.transaction_buffer.key(),
+            creator: transaction_buffer.creator,
+            buffer_index: transaction_buffer.buffer_index,
+        });
+
         Ok(())
     }
 }
 
 #[derive(Accounts)]
-pub struct SuperTransactionAccountsClose<'info> {
-    #[account(
-        mut,
-        has_one = creator,
-        close = creator
-    )]
-    pub transaction: Account<'info, SuperTransaction>,
+pub struct SuperTransactionRealloc<'info> {
+    /// CHECK: only the header is deserialized, the message is written in place.
+    #[account(mut, owner = crate::ID @ SuperTxnError::IllegalAccountOwner)]
+    pub transaction: AccountInfo<'info>,
 
+    /// CHECK: checked below.
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub transaction_buffer: AccountInfo<'info>,
+
+    /// The creator of the transaction and the transaction buffer.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The payer for the additional rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SuperTransactionRealloc<'_> {
+    pub fn validate<'a>(
+        &self,
+        transaction: &SuperTransaction,
+        txn_buffer_account_data: &'a [u8],
+    ) -> Result<TransactionBufferRef<&'a [u8]>> {
+        require_keys_eq!(
+            transaction.creator,
+            self.creator.key(),
+            SuperTxnError::Unauthorized
+        );
+        require!(
+            transaction.is_pending(),
+            SuperTxnError::TransactionNotPending
+        );
+
+        let transaction_buffer = TransactionBufferRef::load(txn_buffer_account_data)?;
+
+        // Validate that the buffer is complete and matches the final hash and size
+        transaction_buffer.validate_finalized()?;
+        require_eq!(
+            transaction_buffer.header.creator,
+            self.creator.key(),
+            SuperTxnError::Unauthorized
+        );
+        // Only the buffer the transaction was created from can complete it.
+        require!(
+            transaction.pending_message_hash == Some(transaction_buffer.header.final_buffer_hash),
+            SuperTxnError::FinalBufferHashMismatch
+        );
+        Ok(transaction_buffer)
+    }
+
+    /// Grow the pending super transaction and write the message once it fits.
+    pub fn super_transaction_realloc(ctx: Context<Self>) -> Result<()> {
+        let transaction_account_info = &ctx.accounts.transaction;
+        let mut transaction = {
+            let transaction_account_data = transaction_account_info.try_borrow_data()?;
+            SuperTransaction::try_deserialize_header(&mut &**transaction_account_data)?
+        };
+
+        // The buffer is read in place from the account data
+        let transaction_buffer_account_info = ctx.accounts.transaction_buffer.to_account_info();
+        let txn_buffer_account_data = transaction_buffer_account_info.try_borrow_data()?;
+        let transaction_buffer = ctx
+            .accounts
+            .validate(&transaction, &txn_buffer_account_data)?;
+
+        let ephemeral_signers = u8::try_from(transaction.ephemeral_signer_bumps.len()).unwrap();
+        let new_len = SuperTransaction::size(
+            ephemeral_signers,
+            transaction_buffer.buffer(),
+            transaction.memo.as_deref(),
+            transaction
+                .approval
+                .as_ref()
+                .map(|approval| approval.members.len()),
+            &transaction.assertions,
+        )?;
+        let step_len = new_len.min(
+            transaction_account_info
+                .data_len()
+                .saturating_add(MAX_PERMITTED_DATA_INCREASE),
+        );
+
+        realloc(
+            transaction_account_info,
+            step_len,
+            transaction.bounty_lamports,
+            &ctx.accounts.rent_payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        if step_len < new_len {
+            msg!("SuperTransaction is {} of {} bytes", step_len, new_len);
+            return Ok(());
+        }
+
+        // The message is converted from the buffer straight into the account data,
+        // it doesn't have to fit on the heap.
+        let transaction_message =
+            SuperTransactionMessageRef::from_transaction_message(transaction_buffer.buffer())?;
+        transaction.complete_pending(&transaction_message)?;
+
+        let mut header = Vec::new();
+        transaction.try_serialize_header(&mut header)?;
+        let mut transaction_account_data = transaction_account_info.try_borrow_mut_data()?;
+        require!(
+            header.len() <= transaction_account_data.len(),
+            SuperTxnError::AccountNotLargeEnough
+        );
+        let (header_data, mut message_data) = transaction_account_data.split_at_mut(header.len());
+        header_data.copy_from_slice(&header);
+        transaction_message
+            .serialize(&mut message_data)
+            .map_err(|_| SuperTxnError::AccountNotLargeEnough)?;
+        drop(transaction_account_data);
+
+        let transaction_buffer = transaction_buffer.header;
+        drop(txn_buffer_account_data);
+
+        close(
+            ctx.accounts.transaction_buffer.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+        )?;
+
+        emit!(BufferClosed {
+            transaction_buffer: ctx.accounts.transaction_buffer.key(),
+            creator: transaction_buffer.creator,
+            buffer_index: transaction_buffer.buffer_index,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SuperTransactionExecuteArgs {
+    /// Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist.
+    pub strict: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SuperTransactionExecuteRangeArgs {
+    /// Maximum number of instructions to execute in this call.
+    pub max_instructions: u8,
+    /// Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist.
+    pub strict: bool,
+    /// Stop before an instruction once fewer compute units than this are left,
+    /// the progress is saved so the next call resumes from there.
+    pub min_remaining_cu: Option<u64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SuperTransactionExecuteBestEffortArgs {
+    /// Keep the transaction account with its skipped instructions bitmap instead of closing it.
+    pub keep_after_execute: bool,
+}
+
+#[derive(Accounts)]
+pub struct SuperTransactionExecute<'info> {
+    /// The transaction to execute.
+    /// CHECK: checked below
+    #[account(mut)]
+    pub transaction: AccountInfo<'info>,
+    /// The creator of the transaction, receives the rent refund.
+    /// CHECK: checked against `transaction.creator` below
+    #[account(mut)]
+    pub creator: AccountInfo<'info>,
+    /// The signer executing the transaction, must be allowed by `transaction.executor`.
+    /// Receives the bounty once the transaction is fully executed,
+    /// pays the rent of the captured return data.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    /// The execution policy of the creator, applied if it has been created.
+    /// CHECK: address checked by the seeds constraint, deserialized below.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_EXECUTION_POLICY, creator.key().as_ref()],
+        bump
+    )]
+    pub execution_policy: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    // `remaining_accounts` must include the following accounts in the exact order:
+    // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
+    // 2. Accounts in the order they appear in `message.account_keys`.
+    // 3. Accounts in the order they appear in `message.address_table_lookups`.
+}
+
+impl SuperTransactionExecute<'_> {
+    /// Execute the super transaction.
+    pub fn super_transaction_execute(
+        ctx: Context<Self>,
+        args: SuperTransactionExecuteArgs,
+    ) -> Result<()> {
+        Self::execute(ctx, None, args.strict, false, false, None)
+    }
+
+    /// Execute up to `max_instructions` of the super transaction, starting from
+    /// its `next_instruction_index`.
+    pub fn super_transaction_execute_range(
+        ctx: Context<Self>,
+        args: SuperTransactionExecuteRangeArgs,
+    ) -> Result<()> {
+        require!(
+            args.max_instructions > 0,
+            SuperTxnError::InvalidInstructionArgs
+        );
+        Self::execute(
+            ctx,
+            Some(args.max_instructions),
+            args.strict,
+            false,
+            false,
+            args.min_remaining_cu,
+        )
+    }
+
+    /// Execute the remaining instructions of the super transaction in best-effort mode.
+    pub fn super_transaction_execute_best_effort(
+        ctx: Context<Self>,
+        args: SuperTransactionExecuteBestEffortArgs,
+    ) -> Result<()> {
+        Self::execute(ctx, None, true, true, args.keep_after_execute, None)
+    }
+
+    /// Executes the remaining instructions of the super transaction, or at most
+    /// `max_instructions` of them. Progress is saved if instructions are left
+    /// or `keep_after_execute` is set, otherwise the transaction account is closed.
+    /// Execution stops early once fewer than `min_remaining_compute_units` are left.
+    fn execute(
+        ctx: Context<Self>,
+        max_instructions: Option<u8>,
+        strict: bool,
+        best_effort: bool,
+        keep_after_execute: bool,
+        min_remaining_compute_units: Option<u64>,
+    ) -> Result<()> {
+        let transaction_account_info = &ctx.accounts.transaction;
+        if transaction_account_info.data_is_empty() {
+            require!(!strict, SuperTxnError::MissingAccount);
+            msg!("Warning: SuperTransaction is empty, fail silently for jito bundles");
+            return Ok(());
+        }
+        // The program signs for the creator authority, the transaction can't be forged.
+        require_keys_eq!(
+            *transaction_account_info.owner,
+            crate::ID,
+            SuperTxnError::IllegalAccountOwner
+        );
+        // The message is borrowed from the account data rather than deserialized on the heap,
+        // which is never freed by the bump allocator. The account can't be writable in the CPIs,
+        // so the data stays borrowed while they are executed.
+        let transaction_account_data = transaction_account_info.try_borrow_data()?;
+        let mut buf: &[u8] = &transaction_account_data;
+        let mut transaction = SuperTransaction::try_deserialize_header(&mut buf)?;
+        require!(!transaction.is_pending(), SuperTxnError::TransactionPending);
+        // Batch transactions are executed in order with `batch_execute_next`.
+        require!(
+            transaction.batch.is_none(),
+            SuperTxnError::TransactionInBatch
+        );
+        let message_start = transaction_account_data.len() - buf.len();
+        let message = SuperTransactionMessageRef::deserialize(&mut buf)?;
+        let message_range = message_start..transaction_account_data.len() - buf.len();
+        require_eq!(
+            transaction.creator,
+            ctx.accounts.creator.key(),
+            SuperTxnError::Unauthorized
+        );
+        require!(
+            transaction
+                .executor
+                .can_execute(&transaction.creator, ctx.accounts.executor.key),
+            SuperTxnError::Unauthorized
+        );
+        let clock = Clock::get()?;
+        transaction.check_validity(&clock)?;
+        if let Some(approval) = &transaction.approval {
+            approval.require_executable(clock.unix_timestamp)?;
+        }
+
+        let num_instructions = message.num_instructions();
+        let start = usize::from(transaction.next_instruction_index);
+        let end = max_instructions.map_or(num_instructions, |max_instructions| {
+            start
+                .saturating_add(usize::from(max_instructions))
+                .min(num_instructions)
+        });
+
+        let execution_policy =
+            ExecutionPolicy::load(&ctx.accounts.execution_policy)?.unwrap_or_default();
+
+        let mode = ExecutionMode {
+            best_effort,
+            capture_return_data: transaction.capture_return_data,
+            min_remaining_compute_units,
+        };
+        let outcome = Self::execute_instructions(
+            ctx.accounts.transaction.key(),
+            &transaction,
+            &message,
+            ctx.accounts.executor.key(),
+            &execution_policy,
+            ctx.remaining_accounts,
+            start..end,
+            mode,
+        )?;
+        drop(transaction_account_data);
+
+        if !outcome.skipped_instructions.is_empty() {
+            for &index in &outcome.skipped_instructions {
+                transaction.mark_skipped(index);
+            }
+            emit!(InstructionsSkipped {
+                transaction: transaction_account_info.key(),
+                skipped_instructions: transaction.skipped_instructions.clone(),
+            });
+        }
+
+        // Execution may have stopped before the end of the range.
+        let end = outcome.next_instruction_index;
+
+        let last_return_data = outcome.return_data.last().map(|result| result.data.clone());
+        if transaction.capture_return_data {
+            transaction.return_data.extend(outcome.return_data);
+            transaction.compute_units.extend(outcome.compute_units);
+        }
+
+        if end < num_instructions {
+            // Save progress, the remaining instructions are executed by the next call.
+            transaction.next_instruction_index = u8::try_from(end).unwrap();
+            ctx.accounts.save(&transaction, message_range.clone())?;
+            if let Some(data) = last_return_data {
+                set_return_data(&data);
+            }
+            msg!(
+                "Executed instructions {}..{} of {}",
+                start,
+                end,
+                num_instructions
+            );
+            return Ok(());
+        }
+
+        // Every CPI succeeded, pay the bounty out of the escrow before the rent is refunded.
+        if transaction.bounty_lamports > 0 {
+            transaction.check_bounty_escrow(
+                transaction_account_info.lamports(),
+                transaction_account_info.data_len(),
+            )?;
+            **transaction_account_info.try_borrow_mut_lamports()? -= transaction.bounty_lamports;
+            **ctx.accounts.executor.try_borrow_mut_lamports()? += transaction.bounty_lamports;
+
+            emit!(BountyPaid {
+                transaction: transaction_account_info.key(),
+                executor: ctx.accounts.executor.key(),
+                lamports: transaction.bounty_lamports,
+            });
+            transaction.bounty_lamports = 0;
+        }
+
+        if keep_after_execute || transaction.capture_return_data {
+            // Keep the skipped instructions bitmap and the captured return data for off-chain
+            // tooling, the account can be closed with `super_transaction_accounts_close`.
+            transaction.next_instruction_index = u8::try_from(end).unwrap();
+            ctx.accounts.save(&transaction, message_range.clone())?;
+            if let Some(data) = last_return_data {
+                set_return_data(&data);
+            }
+            return Ok(());
+        }
+
+        close(
+            ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+        )?;
+
+        emit!(SuperTransactionClosed {
+            transaction: ctx.accounts.transaction.key(),
+            creator: transaction.creator,
+            transaction_index: transaction.index,
+        });
+
+        Ok(())
+    }
+
+    /// Executes `instruction_range` of the transaction message via CPI calls.
+    /// `remaining_accounts` must be laid out as documented on `SuperTransactionExecute`.
+    /// The program signs for the ephemeral signers and the creator authority PDA.
+    /// Returns the skipped instructions and the return data captured in `mode`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_instructions<M: TransactionMessageView>(
+        transaction_key: Pubkey,
+        transaction: &SuperTransaction,
+        transaction_message: &M,
+        executor: Pubkey,
+        execution_policy: &ExecutionPolicy,
+        remaining_accounts: &[AccountInfo],
+        instruction_range: Range<usize>,
+        mode: ExecutionMode,
+    ) -> Result<ExecutionOutcome> {
+        let creator_key = transaction.creator;
+        let num_lookups = transaction_message.num_address_table_lookups();
+
+        let message_account_infos = remaining_accounts
+            .get(num_lookups..)
+            .ok_or(SuperTxnError::InvalidNumberOfAccounts)?;
+        let address_lookup_table_account_infos = remaining_accounts
+            .get(..num_lookups)
+            .ok_or(SuperTxnError::InvalidNumberOfAccounts)?;
+
+        let (mut signer_keys, mut signer_seeds) =
+            derive_ephemeral_signers(transaction_key, &transaction.ephemeral_signer_bumps);
+        let (creator_authority_key, creator_authority_seeds) =
+            derive_creator_authority(&creator_key);
+        signer_keys.push(creator_authority_key);
+        signer_seeds.push(creator_authority_seeds);
+
+        let executable_message = ExecutableTransactionMessage::new_validated(
+            transaction_message,
+            message_account_infos,
+            address_lookup_table_account_infos,
+            &transaction_key,
+            &signer_keys,
+            execution_policy,
+        )?;
+
+        // Execute the transaction message instructions one-by-one.
+        let compute_units_before = sol_remaining_compute_units();
+        let outcome =
+            executable_message.execute_message(instruction_range.clone(), &signer_seeds, mode)?;
+        let compute_units_consumed =
+            compute_units_before.saturating_sub(sol_remaining_compute_units());
+
+        // Check the outcome once the last instruction has been executed.
+        if outcome.next_instruction_index == transaction_message.num_instructions() {
+            for (index, assertion) in transaction.assertions.iter().enumerate() {
+                let account_info = executable_message
+                    .get_account_by_index(usize::from(assertion.account_index()))?;
+                assertion.check(account_info).map_err(|err| {
+                    msg!("Assertion {} failed", index);
+                    err
+                })?;
+            }
+        }
+
+        emit!(SuperTransactionExecuted {
+            transaction: transaction_key,
+            creator: creator_key,
+            transaction_index: transaction.index,
+            executor,
+            instructions_executed: u8::try_from(
+                outcome.next_instruction_index - instruction_range.start,
+            )
+            .unwrap(),
+            next_instruction_index: u8::try_from(outcome.next_instruction_index).unwrap(),
+            compute_units_consumed,
+        });
+        // Helps tuning the heap frame requested by the transaction and the `heap-*` features.
+        msg!("Heap used: {} of {} bytes", heap_used(), HEAP_SIZE);
+
+        Ok(outcome)
+    }
+
+    /// Serializes `transaction` back to its account, in front of its message found at
+    /// `message_range` of the account data, resizing the account to `SuperTransaction::account_size`.
+    /// The captured results can't use up the room reserved for the remaining votes.
+    fn save(&self, transaction: &SuperTransaction, message_range: Range<usize>) -> Result<()> {
+        let transaction_account_info = &self.transaction;
+        let mut header = Vec::new();
+        transaction.try_serialize_header(&mut header)?;
+        let len = transaction.account_size(header.len(), message_range.len());
+        if len > transaction_account_info.data_len() {
+            realloc(
+                transaction_account_info,
+                len,
+                transaction.bounty_lamports,
+                &self.executor.to_account_info(),
+                &self.system_program.to_account_info(),
+            )?;
+        }
+        {
+            let mut data = transaction_account_info.try_borrow_mut_data()?;
+            data.copy_within(message_range, header.len());
+            data[..header.len()].copy_from_slice(&header);
+        }
+        // Drop the stale bytes left behind the message.
+        if len < transaction_account_info.data_len() {
+            transaction_account_info.realloc(len, false)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SuperTransactionVote<'info> {
+    /// CHECK: only the header is deserialized below, large messages don't fit on the heap.
+    #[account(mut, owner = crate::ID @ SuperTxnError::IllegalAccountOwner)]
+    pub transaction: AccountInfo<'info>,
+
+    /// The approval policy member casting the vote.
+    pub member: Signer<'info>,
+}
+
+impl SuperTransactionVote<'_> {
+    /// Record the vote of `member` on the transaction approval policy.
+    pub fn super_transaction_vote(ctx: Context<Self>, vote: Vote) -> Result<()> {
+        let transaction_account_info = &ctx.accounts.transaction;
+        let member = ctx.accounts.member.key();
+        let mut transaction_account_data = transaction_account_info.try_borrow_mut_data()?;
+        let mut buf: &[u8] = &transaction_account_data;
+        let mut transaction = SuperTransaction::try_deserialize_header(&mut buf)?;
+        require!(!transaction.is_pending(), SuperTxnError::TransactionPending);
+        let message_start = transaction_account_data.len() - buf.len();
+        SuperTransactionMessageRef::deserialize(&mut buf)?;
+        let message_range = message_start..transaction_account_data.len() - buf.len();
+
+        let approval = transaction
+            .approval
+            .as_mut()
+            .ok_or(SuperTxnError::ApprovalPolicyMissing)?;
+        approval.vote(member, vote, Clock::get()?.unix_timestamp)?;
+        let status = approval.status;
+
+        // The account keeps room for every vote, see `SuperTransaction::account_size`.
+        let mut header = Vec::new();
+        transaction.try_serialize_header(&mut header)?;
+        require!(
+            header.len() + message_range.len() <= transaction_account_data.len(),
+            SuperTxnError::AccountNotLargeEnough
+        );
+        transaction_account_data.copy_within(message_range, header.len());
+        transaction_account_data[..header.len()].copy_from_slice(&header);
+
+        emit!(SuperTransactionVoted {
+            transaction: transaction_account_info.key(),
+            creator: transaction.creator,
+            transaction_index: transaction.index,
+            member,
+            vote,
+            status,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SuperTransactionAccountsClose<'info> {
+    /// CHECK: only the header is deserialized below, large messages don't fit on the heap.
+    #[account(mut, owner = crate::ID @ SuperTxnError::IllegalAccountOwner)]
+    pub transaction: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SuperTransactionAccountsClose<'_> {
+    /// Close the super transaction.
+    pub fn super_transaction_accounts_close(ctx: Context<Self>) -> Result<()> {
+        let transaction_account_info = &ctx.accounts.transaction;
+        let transaction = {
+            let transaction_account_data = transaction_account_info.try_borrow_data()?;
+            SuperTransaction::try_deserialize_header(&mut &**transaction_account_data)?
+        };
+        require_keys_eq!(
+            transaction.creator,
+            ctx.accounts.creator.key(),
+            SuperTxnError::Unauthorized
+        );
+        // Batch transactions are closed with `batch_close`.
+        require!(
+            transaction.batch.is_none(),
+            SuperTxnError::TransactionInBatch
+        );
+
+        close(
+            transaction_account_info.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+        )?;
+
+        emit!(SuperTransactionClosed {
+            transaction: transaction_account_info.key(),
+            creator: transaction.creator,
+            transaction_index: transaction.index,
+        });
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BatchCreateArgs {
+    /// Index of the batch account to seed the account derivation
+    pub batch_index: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(args: BatchCreateArgs)]
+pub struct BatchCreate<'info> {
+    #[account(
+        init,
+        payer = rent_payer,
+        space = Batch::SIZE,
+        seeds = [
+            SEED_PREFIX,
+            SEED_BATCH,
+            creator.key().as_ref(),
+            &args.batch_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub batch: Account<'info, Batch>,
+
+    /// The creator of the batch and its transactions.
+    pub creator: Signer<'info>,
+
+    /// The payer for the rent of the batch and all of its transactions.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl BatchCreate<'_> {
+    /// Create a new batch.
+    pub fn batch_create(ctx: Context<Self>, args: BatchCreateArgs) -> Result<()> {
+        let batch = &mut ctx.accounts.batch;
+
+        batch.creator = ctx.accounts.creator.key();
+        batch.index = args.batch_index;
+        batch.bump = ctx.bumps.batch;
+        batch.rent_payer = ctx.accounts.rent_payer.key();
+        batch.size = 0;
+        batch.next_transaction_index = 0;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BatchAddTransactionArgs {
+    /// Number of ephemeral signing PDAs required by the transaction.
+    pub ephemeral_signers: u8,
+    pub transaction_message: Vec<u8>,
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: BatchAddTransactionArgs)]
+pub struct BatchAddTransaction<'info> {
+    #[account(
+        mut,
+        has_one = creator @ SuperTxnError::Unauthorized,
+        has_one = rent_payer @ SuperTxnError::InvalidRentCollector,
+        seeds = [
+            SEED_PREFIX,
+            SEED_BATCH,
+            creator.key().as_ref(),
+            &batch.index.to_le_bytes(),
+        ],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, Batch>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = SuperTransaction::size(
+            args.ephemeral_signers,
+            &args.transaction_message,
+            args.memo.as_deref(),
+            None,
+            &[],
+        )?,
+        seeds = [
+            SEED_PREFIX,
+            batch.key().as_ref(),
+            SEED_BATCH_TRANSACTION,
+            &batch.size.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction: Account<'info, SuperTransaction>,
+
+    /// The creator of the batch.
+    pub creator: Signer<'info>,
+
+    /// The payer for the transaction account rent, must be the batch rent payer.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl BatchAddTransaction<'_> {
+    /// Add a new super transaction to the batch.
+    pub fn batch_add_transaction(ctx: Context<Self>, args: BatchAddTransactionArgs) -> Result<()> {
+        let batch = &mut ctx.accounts.batch;
+        let transaction = &mut ctx.accounts.transaction;
+
+        let transaction_message =
+            TransactionMessage::deserialize(&mut args.transaction_message.as_slice())?;
+        let transaction_key = transaction.key();
+        let clock = Clock::get()?;
+
+        // Initialize the transaction fields, the index is the position in the batch.
+        transaction.creator = batch.creator;
+        transaction.index = batch.size;
+        transaction.bump = ctx.bumps.transaction;
+        transaction.created_at_slot = clock.slot;
+        transaction.created_at_unix = clock.unix_timestamp;
+        transaction.memo = args.memo.clone();
+        transaction.ephemeral_signer_bumps =
+            derive_ephemeral_signer_bumps(&transaction_key, args.ephemeral_signers);
+        transaction.batch = Some(batch.key());
+        transaction.set_message(transaction_message.try_into()?)?;
+
+        batch.size = batch.size.checked_add(1).ok_or(SuperTxnError::BatchFull)?;
+
+        emit!(SuperTransactionCreated {
+            transaction: transaction_key,
+            creator: batch.creator,
+            transaction_index: transaction.index,
+            message_hash: hash(&args.transaction_message).to_bytes(),
+            memo: args.memo,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct BatchExecuteNext<'info> {
+    #[account(
+        mut,
+        has_one = creator @ SuperTxnError::Unauthorized,
+        has_one = rent_payer @ SuperTxnError::InvalidRentCollector,
+        seeds = [
+            SEED_PREFIX,
+            SEED_BATCH,
+            creator.key().as_ref(),
+            &batch.index.to_le_bytes(),
+        ],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, Batch>,
+
+    /// The next transaction of the batch to execute.
+    /// CHECK: only the header is deserialized below, large messages don't fit on the heap.
+    #[account(
+        mut,
+        // Only the program sets the batch of a transaction, so checking the owner and the
+        // batch and index in the header stands in for the seeds constraint
+        owner = crate::ID @ SuperTxnError::IllegalAccountOwner,
+    )]
+    pub transaction: AccountInfo<'info>,
+
+    pub creator: Signer<'info>,
+
+    /// The execution policy of the creator, applied if it has been created.
+    /// CHECK: address checked by the seeds constraint, deserialized below.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_EXECUTION_POLICY, creator.key().as_ref()],
+        bump
+    )]
+    pub execution_policy: AccountInfo<'info>,
+
+    /// The batch rent payer, receives the rent of the executed transaction
+    /// and of the batch once it is complete.
+    /// CHECK: checked against `batch.rent_payer`.
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+    // `remaining_accounts` must be laid out as documented on `SuperTransactionExecute`.
+}
+
+impl BatchExecuteNext<'_> {
+    /// Execute the next transaction of the batch and close it.
+    pub fn batch_execute_next(ctx: Context<Self>) -> Result<()> {
+        let transaction_account_info = &ctx.accounts.transaction;
+        let batch = &ctx.accounts.batch;
+        // The message is borrowed from the account data, as in `SuperTransactionExecute::execute`.
+        let transaction_account_data = transaction_account_info.try_borrow_data()?;
+        let mut buf: &[u8] = &transaction_account_data;
+        let transaction = SuperTransaction::try_deserialize_header(&mut buf)?;
+        require!(
+            transaction.batch == Some(batch.key())
+                && transaction.index == batch.next_transaction_index,
+            SuperTxnError::InvalidBatchTransactions
+        );
+        let message = SuperTransactionMessageRef::deserialize(&mut buf)?;
+        let num_instructions = message.num_instructions();
+        let start = usize::from(transaction.next_instruction_index);
+
+        let execution_policy =
+            ExecutionPolicy::load(&ctx.accounts.execution_policy)?.unwrap_or_default();
+
+        SuperTransactionExecute::execute_instructions(
+            transaction_account_info.key(),
+            &transaction,
+            &message,
+            ctx.accounts.creator.key(),
+            &execution_policy,
+            ctx.remaining_accounts,
+            start..num_instructions,
+            ExecutionMode::default(),
+        )?;
+        drop(transaction_account_data);
+
+        close(
+            transaction_account_info.to_account_info(),
+            ctx.accounts.rent_payer.to_account_info(),
+        )?;
+
+        emit!(SuperTransactionClosed {
+            transaction: transaction_account_info.key(),
+            creator: transaction.creator,
+            transaction_index: transaction.index,
+        });
+
+        let batch = &mut ctx.accounts.batch;
+        batch.next_transaction_index += 1;
+        msg!(
+            "Executed batch transaction {} of {}",
+            batch.next_transaction_index,
+            batch.size
+        );
+
+        if batch.is_complete() {
+            close(
+                batch.to_account_info(),
+                ctx.accounts.rent_payer.to_account_info(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct BatchClose<'info> {
+    #[account(
+        mut,
+        has_one = creator @ SuperTxnError::Unauthorized,
+        has_one = rent_payer @ SuperTxnError::InvalidRentCollector,
+        seeds = [
+            SEED_PREFIX,
+            SEED_BATCH,
+            creator.key().as_ref(),
+            &batch.index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+        close = rent_payer
+    )]
+    pub batch: Account<'info, Batch>,
+
+    pub creator: Signer<'info>,
+
+    /// The batch rent payer, receives the rent of the batch and its transactions.
+    /// CHECK: checked against `batch.rent_payer`.
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+    // `remaining_accounts` are the transactions of the batch that haven't been executed, in order.
+}
+
+impl<'info> BatchClose<'info> {
+    /// Close the transactions of the batch that haven't been executed,
+    /// the batch itself is closed by the account constraints.
+    pub fn batch_close(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let batch = &ctx.accounts.batch;
+        let batch_key = batch.key();
+        let transaction_indexes = batch.next_transaction_index..batch.size;
+
+        require_eq!(
+            ctx.remaining_accounts.len(),
+            transaction_indexes.len(),
+            SuperTxnError::InvalidBatchTransactions
+        );
+
+        for (transaction_account_info, transaction_index) in
+            ctx.remaining_accounts.iter().zip(transaction_indexes)
+        {
+            require_keys_eq!(
+                *transaction_account_info.owner,
+                crate::ID,
+                SuperTxnError::IllegalAccountOwner
+            );
+            let transaction = {
+                let transaction_account_data = transaction_account_info.try_borrow_data()?;
+                SuperTransaction::try_deserialize_header(&mut &**transaction_account_data)?
+            };
+            require!(
+                transaction.batch == Some(batch_key) && transaction.index == transaction_index,
+                SuperTxnError::InvalidBatchTransactions
+            );
+
+            close(
+                transaction_account_info.clone(),
+                ctx.accounts.rent_payer.to_account_info(),
+            )?;
+
+            emit!(SuperTransactionClosed {
+                transaction: transaction_account_info.key(),
+                creator: transaction.creator,
+                transaction_index,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SuperTransactionCloseExpired<'info> {
+    /// CHECK: only the header is deserialized below, large messages don't fit on the heap.
+    #[account(mut, owner = crate::ID @ SuperTxnError::IllegalAccountOwner)]
+    pub transaction: AccountInfo<'info>,
+
+    /// The creator of the transaction, receives the rent refund.
+    /// CHECK: checked against the transaction creator.
+    #[account(mut)]
+    pub creator: AccountInfo<'info>,
+}
+
+impl SuperTransactionCloseExpired<'_> {
+    /// Close the expired super transaction.
+    pub fn super_transaction_close_expired(ctx: Context<Self>) -> Result<()> {
+        let transaction_account_info = &ctx.accounts.transaction;
+        let transaction = {
+            let transaction_account_data = transaction_account_info.try_borrow_data()?;
+            SuperTransaction::try_deserialize_header(&mut &**transaction_account_data)?
+        };
+        require_keys_eq!(
+            transaction.creator,
+            ctx.accounts.creator.key(),
+            SuperTxnError::Unauthorized
+        );
+        require!(!transaction.is_pending(), SuperTxnError::TransactionPending);
+        require!(
+            transaction.batch.is_none(),
+            SuperTxnError::TransactionInBatch
+        );
+        require!(
+            transaction.is_expired(&Clock::get()?),
+            SuperTxnError::TransactionNotExpired
+        );
+
+        close(
+            transaction_account_info.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+        )?;
+
+        emit!(SuperTransactionClosed {
+            transaction: transaction_account_info.key(),
+            creator: transaction.creator,
+            transaction_index: transaction.index,
+        });
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecutionPolicyArgs {
+    /// If not empty, only these programs can be called.
+    pub allowed_programs: Vec<Pubkey>,
+    /// Programs that can never be called.
+    pub denied_programs: Vec<Pubkey>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ExecutionPolicyArgs)]
+pub struct ExecutionPolicyCreate<'info> {
+    #[account(
+        init,
+        payer = rent_payer,
+        space = ExecutionPolicy::size(args.allowed_programs.len(), args.denied_programs.len()),
+        seeds = [SEED_PREFIX, SEED_EXECUTION_POLICY, creator.key().as_ref()],
+        bump
+    )]
+    pub execution_policy: Account<'info, ExecutionPolicy>,
+
+    pub creator: Signer<'info>,
+
+    /// The payer for the execution policy account rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl ExecutionPolicyCreate<'_> {
+    /// Create the execution policy of the creator.
+    pub fn execution_policy_create(ctx: Context<Self>, args: ExecutionPolicyArgs) -> Result<()> {
+        let execution_policy = &mut ctx.accounts.execution_policy;
+
+        execution_policy.creator = ctx.accounts.creator.key();
+        execution_policy.bump = ctx.bumps.execution_policy;
+        execution_policy.allowed_programs = args.allowed_programs;
+        execution_policy.denied_programs = args.denied_programs;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(args: ExecutionPolicyArgs)]
+pub struct ExecutionPolicyUpdate<'info> {
+    #[account(
+        mut,
+        has_one = creator @ SuperTxnError::Unauthorized,
+        seeds = [SEED_PREFIX, SEED_EXECUTION_POLICY, creator.key().as_ref()],
+        bump = execution_policy.bump,
+        realloc = ExecutionPolicy::size(args.allowed_programs.len(), args.denied_programs.len()),
+        realloc::payer = rent_payer,
+        realloc::zero = false,
+    )]
+    pub execution_policy: Account<'info, ExecutionPolicy>,
+
+    pub creator: Signer<'info>,
+
+    /// The payer for the additional rent, refunded if the policy shrinks.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
+
+impl ExecutionPolicyUpdate<'_> {
+    /// Replace the program lists of the execution policy, the realloc is done by the account constraints.
+    pub fn execution_policy_update(ctx: Context<Self>, args: ExecutionPolicyArgs) -> Result<()> {
+        let execution_policy = &mut ctx.accounts.execution_policy;
+
+        execution_policy.allowed_programs = args.allowed_programs;
+        execution_policy.denied_programs = args.denied_programs;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ExecutionPolicyClose<'info> {
+    #[account(
+        mut,
+        has_one = creator @ SuperTxnError::Unauthorized,
+        close = creator,
+        seeds = [SEED_PREFIX, SEED_EXECUTION_POLICY, creator.key().as_ref()],
+        bump = execution_policy.bump
+    )]
+    pub execution_policy: Account<'info, ExecutionPolicy>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+impl ExecutionPolicyClose<'_> {
+    /// Close the execution policy, the account itself is closed by the account constraints.
+    pub fn execution_policy_close(_ctx: Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+
+/// Maximum time lock of an `ApprovalPolicy`, 90 days in seconds.
+pub const MAX_TIME_LOCK: u32 = 90 * 24 * 60 * 60;
+
+/// Optional set of members that have to approve a `SuperTransaction` before it can be executed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct ApprovalPolicy {
+    /// Members allowed to vote on the transaction.
+    pub members: Vec<Pubkey>,
+    /// Number of approvals required for the transaction to be `Approved`.
+    pub threshold: u16,
+    /// Number of seconds that must pass between approval and execution.
+    pub time_lock: u32,
+    /// Current status of the transaction.
+    pub status: ApprovalStatus,
+    /// Members that approved the transaction.
+    pub approved: Vec<Pubkey>,
+    /// Members that rejected the transaction.
+    pub rejected: Vec<Pubkey>,
+    /// Members that cancelled the approved transaction.
+    pub cancelled: Vec<Pubkey>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum ApprovalStatus {
+    /// Transaction is open for voting.
+    #[default]
+    Active,
+    /// Transaction reached the threshold and can be executed once the time lock is released.
+    Approved { timestamp: i64 },
+    /// Transaction can no longer reach the threshold.
+    Rejected { timestamp: i64 },
+    /// Approved transaction was cancelled by a threshold of members.
+    Cancelled { timestamp: i64 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Vote {
+    Approve,
+    Reject,
+    Cancel,
+}
+
+impl ApprovalPolicy {
+    pub fn new(mut members: Vec<Pubkey>, threshold: u16, time_lock: u32) -> Result<Self> {
+        require!(!members.is_empty(), SuperTxnError::EmptyMembers);
+        require!(
+            members.len() <= usize::from(u16::MAX),
+            SuperTxnError::TooManyMembers
+        );
+
+        members.sort();
+        let has_duplicates = members.windows(2).any(|pair| pair[0] == pair[1]);
+        require!(!has_duplicates, SuperTxnError::DuplicateMember);
+
+        require!(
+            threshold > 0 && usize::from(threshold) <= members.len(),
+            SuperTxnError::InvalidThreshold
+        );
+        require!(
+            time_lock <= MAX_TIME_LOCK,
+            SuperTxnError::TimeLockExceedsMaxAllowed
+        );
+
+        Ok(Self {
+            members,
+            threshold,
+            time_lock,
+            ..Default::default()
+        })
+    }
+
+    /// Serialized size of a policy with `num_members` members, with room for every member's vote.
+    pub fn size(num_members: usize) -> usize {
+        let members_size = 4 + num_members * 32;
+
+        members_size + // members
+            2 +  // threshold
+            4 +  // time_lock
+            1 + 8 + // status
+            3 * members_size // approved, rejected, cancelled
+    }
+
+    /// Bytes of `ApprovalPolicy::size` not used yet, left for the votes the policy can still record.
+    pub fn unused_size(&self) -> usize {
+        let status_size = match self.status {
+            ApprovalStatus::Active => 1,
+            _ => 1 + 8,
+        };
+        let votes = self.approved.len() + self.rejected.len() + self.cancelled.len();
+        let used_size = 4 + self.members.len() * 32 + // members
+            2 + 4 + status_size + // threshold, time_lock, status
+            3 * 4 + votes * 32; // approved, rejected, cancelled
+
+        Self::size(self.members.len()) - used_size
+    }
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.binary_search(key).is_ok()
+    }
+
+    /// Records `vote` of `member` at `timestamp` and updates the status.
+    pub fn vote(&mut self, member: Pubkey, vote: Vote, timestamp: i64) -> Result<()> {
+        require!(self.is_member(&member), SuperTxnError::NotAMember);
+
+        match vote {
+            Vote::Approve => self.approve(member, timestamp),
+            Vote::Reject => self.reject(member, timestamp),
+            Vote::Cancel => self.cancel(member, timestamp),
+        }
+    }
+
+    fn approve(&mut self, member: Pubkey, timestamp: i64) -> Result<()> {
+        require!(
+            self.status == ApprovalStatus::Active,
+            SuperTxnError::InvalidProposalStatus
+        );
+        require!(
+            !self.approved.contains(&member),
+            SuperTxnError::AlreadyApproved
+        );
+
+        // A member can change their mind before the vote is over.
+        self.rejected.retain(|key| key != &member);
+        self.approved.push(member);
+
+        if self.approved.len() >= usize::from(self.threshold) {
+            self.status = ApprovalStatus::Approved { timestamp };
+        }
+        Ok(())
+    }
+
+    fn reject(&mut self, member: Pubkey, timestamp: i64) -> Result<()> {
+        require!(
+            self.status == ApprovalStatus::Active,
+            SuperTxnError::InvalidProposalStatus
+        );
+        require!(
+            !self.rejected.contains(&member),
+            SuperTxnError::AlreadyRejected
+        );
+
+        self.approved.retain(|key| key != &member);
+        self.rejected.push(member);
+
+        // Rejected once the remaining members can no longer reach the threshold.
+        let cutoff = self.members.len() - usize::from(self.threshold) + 1;
+        if self.rejected.len() >= cutoff {
+            self.status = ApprovalStatus::Rejected { timestamp };
+        }
+        Ok(())
+    }
+
+    fn cancel(&mut self, member: Pubkey, timestamp: i64) -> Result<()> {
+        require!(
+            matches!(self.status, ApprovalStatus::Approved { .. }),
+            SuperTxnError::InvalidProposalStatus
+        );
+        require!(
+            !self.cancelled.contains(&member),
+            SuperTxnError::AlreadyCancelled
+        );
+
+        self.cancelled.push(member);
+
+        if self.cancelled.len() >= usize::from(self.threshold) {
+            self.status = ApprovalStatus::Cancelled { timestamp };
+        }
+        Ok(())
+    }
+
+    /// Checks that the transaction is approved and its time lock is released at `now`.
+    pub fn require_executable(&self, now: i64) -> Result<()> {
+        let ApprovalStatus::Approved { timestamp } = self.status else {
+            return err!(SuperTxnError::InvalidProposalStatus);
+        };
+        require!(
+            now.saturating_sub(timestamp) >= i64::from(self.time_lock),
+            SuperTxnError::TimeLockNotReleased
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn members(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_new_validates_policy() {
+        assert!(ApprovalPolicy::new(vec![], 1, 0).is_err());
+        assert!(ApprovalPolicy::new(members(2), 0, 0).is_err());
+        assert!(ApprovalPolicy::new(members(2), 3, 0).is_err());
+        assert!(ApprovalPolicy::new(members(2), 1, MAX_TIME_LOCK + 1).is_err());
+
+        let member = Pubkey::new_unique();
+        assert!(ApprovalPolicy::new(vec![member, member], 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_approve_reject_and_cancel() {
+        let members = members(3);
+        let mut policy = ApprovalPolicy::new(members.clone(), 2, 60).unwrap();
+
+        policy.vote(members[0], Vote::Reject, 1).unwrap();
+        policy.vote(members[0], Vote::Approve, 2).unwrap();
+        assert!(policy.vote(members[0], Vote::Approve, 3).is_err());
+        assert!(policy.vote(Pubkey::new_unique(), Vote::Approve, 3).is_err());
+        assert!(policy.require_executable(3).is_err());
+
+        policy.vote(members[1], Vote::Approve, 10).unwrap();
+        assert_eq!(policy.status, ApprovalStatus::Approved { timestamp: 10 });
+        assert!(policy.vote(members[2], Vote::Reject, 11).is_err());
+        assert!(policy.require_executable(69).is_err());
+        policy.require_executable(70).unwrap();
+
+        policy.vote(members[1], Vote::Cancel, 80).unwrap();
+        policy.vote(members[2], Vote::Cancel, 81).unwrap();
+        assert_eq!(policy.status, ApprovalStatus::Cancelled { timestamp: 81 });
+        assert!(policy.require_executable(90).is_err());
+    }
+
+    #[test]
+    fn test_rejected_once_threshold_unreachable() {
+        let members = members(3);
+        let mut policy = ApprovalPolicy::new(members.clone(), 2, 0).unwrap();
+
+        policy.vote(members[0], Vote::Reject, 1).unwrap();
+        assert_eq!(policy.status, ApprovalStatus::Active);
+        policy.vote(members[1], Vote::Reject, 2).unwrap();
+        assert_eq!(policy.status, ApprovalStatus::Rejected { timestamp: 2 });
+    }
+
+    #[test]
+    fn test_size_fits_all_votes() {
+        let members = members(3);
+        let mut policy = ApprovalPolicy::new(members.clone(), 3, 0).unwrap();
+        for member in &members {
+            policy.vote(*member, Vote::Approve, 1).unwrap();
+        }
+        for member in &members {
+            policy.vote(*member, Vote::Cancel, 2).unwrap();
+        }
+
+        assert_eq!(
+            ApprovalPolicy::size(members.len()),
+            policy.try_to_vec().unwrap().len() + 3 * 32
+        );
+        assert_eq!(policy.unused_size(), 3 * 32);
+    }
+}
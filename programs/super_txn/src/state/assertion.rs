@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+
+/// Offset of `amount` in an SPL token account, after `mint` and `owner`.
+pub const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+/// Check run against an account of the message once every instruction has been executed.
+/// `account_index` indexes the accounts of the message, static keys first and then the loaded ones.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum Assertion {
+    /// Lamport balance of the account is within `min..=max`.
+    Lamports {
+        account_index: u8,
+        min: u64,
+        max: u64,
+    },
+    /// `amount` of the SPL token account is within `min..=max`.
+    TokenAmount {
+        account_index: u8,
+        min: u64,
+        max: u64,
+    },
+    /// The account is owned by `owner`.
+    Owner { account_index: u8, owner: Pubkey },
+    /// Account data at `offset` equals `data`.
+    Data {
+        account_index: u8,
+        offset: u32,
+        data: Vec<u8>,
+    },
+}
+
+impl Assertion {
+    pub fn account_index(&self) -> u8 {
+        match self {
+            Assertion::Lamports { account_index, .. }
+            | Assertion::TokenAmount { account_index, .. }
+            | Assertion::Owner { account_index, .. }
+            | Assertion::Data { account_index, .. } => *account_index,
+        }
+    }
+
+    /// Checks the assertion against `account_info`.
+    pub fn check(&self, account_info: &AccountInfo) -> Result<()> {
+        let holds = match self {
+            Assertion::Lamports { min, max, .. } => {
+                (*min..=*max).contains(&account_info.lamports())
+            }
+            Assertion::TokenAmount { min, max, .. } => {
+                let data = account_info.try_borrow_data()?;
+                let amount = data
+                    .get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)
+                    .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()));
+                amount.is_some_and(|amount| (*min..=*max).contains(&amount))
+            }
+            Assertion::Owner { owner, .. } => account_info.owner == owner,
+            Assertion::Data { offset, data, .. } => {
+                let account_data = account_info.try_borrow_data()?;
+                let offset = *offset as usize;
+                account_data.get(offset..offset.saturating_add(data.len())) == Some(data.as_slice())
+            }
+        };
+
+        require!(holds, SuperTxnError::AssertionFailed);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 100;
+        let mut data = vec![0u8; 72];
+        data[64..72].copy_from_slice(&500u64.to_le_bytes());
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        let lamports = |min, max| Assertion::Lamports {
+            account_index: 0,
+            min,
+            max,
+        };
+        assert!(lamports(100, 100).check(&account_info).is_ok());
+        assert!(lamports(101, u64::MAX).check(&account_info).is_err());
+
+        let token_amount = |min, max| Assertion::TokenAmount {
+            account_index: 0,
+            min,
+            max,
+        };
+        assert!(token_amount(0, 500).check(&account_info).is_ok());
+        assert!(token_amount(0, 499).check(&account_info).is_err());
+
+        let owner_assertion = |owner| Assertion::Owner {
+            account_index: 0,
+            owner,
+        };
+        assert!(owner_assertion(owner).check(&account_info).is_ok());
+        assert!(owner_assertion(key).check(&account_info).is_err());
+
+        let data_assertion = |offset, data| Assertion::Data {
+            account_index: 0,
+            offset,
+            data,
+        };
+        assert!(data_assertion(64, vec![244, 1])
+            .check(&account_info)
+            .is_ok());
+        assert!(data_assertion(64, vec![245, 1])
+            .check(&account_info)
+            .is_err());
+        assert!(data_assertion(71, vec![0, 0]).check(&account_info).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// Groups several `SuperTransaction`s of one creator that are executed in order.
+/// Batch transactions are derived from the batch key and their index in the batch.
+#[account]
+#[derive(Default)]
+pub struct Batch {
+    /// Batch creator
+    pub creator: Pubkey,
+    /// Index to seed address derivation
+    pub index: u8,
+    /// PDA bump.
+    pub bump: u8,
+    /// Payer of the rent of the batch and its transactions, refunded when they are closed.
+    pub rent_payer: Pubkey,
+    /// Number of transactions added to the batch.
+    pub size: u8,
+    /// Index of the next transaction to execute.
+    pub next_transaction_index: u8,
+}
+
+impl Batch {
+    pub const SIZE: usize = 8 + // anchor account discriminator
+        32 + // creator
+        1 +  // index
+        1 +  // bump
+        32 + // rent_payer
+        1 +  // size
+        1; // next_transaction_index
+
+    /// Whether every transaction added to the batch has been executed.
+    pub fn is_complete(&self) -> bool {
+        self.next_transaction_index >= self.size
+    }
+}
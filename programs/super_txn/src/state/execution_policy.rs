@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+/// Per-creator restriction of the programs the instructions of its transactions can call.
+/// Without a policy every program but super_txn itself can be called.
+#[account]
+#[derive(Default)]
+pub struct ExecutionPolicy {
+    /// Creator the policy applies to.
+    pub creator: Pubkey,
+    /// PDA bump.
+    pub bump: u8,
+    /// If not empty, only these programs can be called.
+    pub allowed_programs: Vec<Pubkey>,
+    /// Programs that can never be called.
+    pub denied_programs: Vec<Pubkey>,
+}
+
+impl ExecutionPolicy {
+    pub fn size(num_allowed_programs: usize, num_denied_programs: usize) -> usize {
+        8 +   // anchor account discriminator
+        32 +  // creator
+        1 +   // bump
+        4 + num_allowed_programs * 32 + // allowed_programs
+        4 + num_denied_programs * 32 // denied_programs
+    }
+
+    /// Loads the policy from its PDA, `None` if it hasn't been created.
+    pub fn load(account_info: &AccountInfo) -> Result<Option<Self>> {
+        if account_info.data_is_empty() {
+            return Ok(None);
+        }
+        let data = account_info.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut data.as_ref())?))
+    }
+
+    /// Whether an instruction can call `program_id`.
+    /// super_txn is denied unless explicitly allowed.
+    pub fn allows(&self, program_id: &Pubkey) -> bool {
+        if self.denied_programs.contains(program_id) {
+            return false;
+        }
+        if !self.allowed_programs.is_empty() {
+            return self.allowed_programs.contains(program_id);
+        }
+        program_id != &crate::ID
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_allows() {
+        let program_a = Pubkey::new_unique();
+        let program_b = Pubkey::new_unique();
+
+        let default_policy = ExecutionPolicy::default();
+        assert!(default_policy.allows(&program_a));
+        assert!(!default_policy.allows(&crate::ID));
+
+        let denylist = ExecutionPolicy {
+            denied_programs: vec![program_a],
+            ..Default::default()
+        };
+        assert!(!denylist.allows(&program_a));
+        assert!(denylist.allows(&program_b));
+
+        let allowlist = ExecutionPolicy {
+            allowed_programs: vec![program_a, crate::ID],
+            ..Default::default()
+        };
+        assert!(allowlist.allows(&program_a));
+        assert!(allowlist.allows(&crate::ID));
+        assert!(!allowlist.allows(&program_b));
+    }
+}
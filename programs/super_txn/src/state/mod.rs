@@ -1,4 +1,12 @@
-mod transaction;
+mod approval;
+mod assertion;
+mod batch;
+mod execution_policy;
 mod raw_transaction;
+mod transaction;
 mod transaction_buffer;
-pub use {transaction::*, transaction_buffer::*, raw_transaction::*};
\ No newline at end of file
+mod transaction_message_ref;
+pub use {
+    approval::*, assertion::*, batch::*, execution_policy::*, raw_transaction::*, transaction::*,
+    transaction_buffer::*, transaction_message_ref::*,
+};
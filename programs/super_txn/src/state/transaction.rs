@@ -1,11 +1,20 @@
 use anchor_lang::prelude::*;
 #[allow(deprecated)]
 use anchor_lang::solana_program::borsh0_10::get_instance_packed_len;
+use anchor_lang::solana_program::system_instruction::MAX_PERMITTED_DATA_LENGTH;
+use anchor_lang::Discriminator;
 
 use crate::errors::*;
 use crate::state::{CompiledInstruction, MessageAddressTableLookup};
+use crate::utils::rent_exempt_lamports;
 
-use super::TransactionMessage;
+use super::{
+    super_transaction_message_size, ApprovalPolicy, Assertion, TransactionMessage,
+    TransactionMessageView,
+};
+
+/// Maximum length of `SuperTransaction::memo` in bytes.
+pub const MAX_MEMO_LEN: usize = 256;
 
 /// Stores data required for tracking the voting and execution status of a super transaction.
 /// Super transaction is a transaction wraps arbitrary Solana instructions, typically calling into other Solana programs.
@@ -14,6 +23,29 @@ use super::TransactionMessage;
 pub struct SuperTransaction {
     /// SuperTransaction creator
     pub creator: Pubkey,
+    /// Index of the transaction, used to seed the account derivation.
+    pub index: u8,
+    /// PDA bump.
+    pub bump: u8,
+    /// Slot at which the transaction was created.
+    pub created_at_slot: u64,
+    /// Unix timestamp at which the transaction was created.
+    pub created_at_unix: i64,
+    /// Optional memo describing the purpose of the transaction, up to `MAX_MEMO_LEN` bytes.
+    pub memo: Option<String>,
+    /// Optional approval policy, the transaction can only be executed once it is `Approved`.
+    pub approval: Option<ApprovalPolicy>,
+    /// Who is allowed to execute the transaction.
+    pub executor: Executor,
+    /// Lamports escrowed on the account on top of its rent, paid to the executor
+    /// once every instruction has been executed.
+    pub bounty_lamports: u64,
+    /// The transaction can't be executed before this slot or timestamp.
+    pub valid_after: Option<ValidityBound>,
+    /// The transaction can't be executed after this slot or timestamp.
+    pub valid_until: Option<ValidityBound>,
+    /// Checked once every instruction has been executed, the execution reverts if any fails.
+    pub assertions: Vec<Assertion>,
     /// Derivation bumps for additional signers.
     /// Some transactions require multiple signers. Often these additional signers are "ephemeral" keypairs
     /// that are generated on the client with a sole purpose of signing the transaction and be discarded immediately after.
@@ -22,29 +54,331 @@ pub struct SuperTransaction {
     /// during execution the program includes the seeds of these PDAs into the `invoke_signed` calls,
     /// thus "signing" on behalf of these PDAs.
     pub ephemeral_signer_bumps: Vec<u8>,
+    /// Index of the next instruction of `message` to execute.
+    /// Only advances when the transaction is executed in several ranges.
+    pub next_instruction_index: u8,
+    /// Bitmap of the instructions skipped by a best-effort execution, one bit per instruction.
+    pub skipped_instructions: Vec<u8>,
+    /// Capture the return data of the executed instructions into `return_data`.
+    /// The account is then kept open after execution until the creator closes it.
+    pub capture_return_data: bool,
+    /// Return data captured from the executed instructions, in execution order.
+    pub return_data: Vec<InstructionReturnData>,
+    /// Compute units consumed by the executed instructions, in execution order.
+    /// Only recorded along with `return_data` when `capture_return_data` is set.
+    pub compute_units: Vec<InstructionComputeUnits>,
+    /// `final_buffer_hash` of the buffer holding the message while the transaction is pending,
+    /// only that buffer can complete it with `super_transaction_realloc`.
+    pub pending_message_hash: Option<[u8; 32]>,
+    /// The batch the transaction was added to, it can then only be executed and closed
+    /// through the batch, in order.
+    pub batch: Option<Pubkey>,
     /// data required for executing the transaction.
     pub message: SuperTransactionMessage,
 }
 
 impl SuperTransaction {
     #[allow(deprecated)]
-    pub fn size(ephemeral_signers_length: u8, transaction_message: &[u8]) -> Result<usize> {
-        let transaction_message: SuperTransactionMessage =
-            TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
-        let message_size = get_instance_packed_len(&transaction_message).unwrap_or_default();
+    pub fn size(
+        ephemeral_signers_length: u8,
+        transaction_message: &[u8],
+        memo: Option<&str>,
+        approval_members: Option<usize>,
+        assertions: &[Assertion],
+    ) -> Result<usize> {
+        let memo_len = memo.map_or(0, str::len);
+        require!(memo_len <= MAX_MEMO_LEN, SuperTxnError::MemoTooLong);
+
+        // Large buffered messages are sized in place rather than deserialized.
+        let (message_size, num_instructions) = super_transaction_message_size(transaction_message)?;
+        let assertions_size = get_instance_packed_len(&assertions.to_vec()).unwrap_or_default();
 
-        Ok(
-            8 +   // anchor account discriminator
+        let size = 8 +   // anchor account discriminator
             32 +  // creator
+            1 +   // index
+            1 +   // bump
+            8 +   // created_at_slot
+            8 +   // created_at_unix
+            1 + memo.map_or(0, |_| 4 + memo_len) + // memo
+            1 + approval_members.map_or(0, ApprovalPolicy::size) + // approval
+            1 + 32 + // executor
+            8 +   // bounty_lamports
+            2 * (1 + 1 + 8) + // valid_after, valid_until
+            assertions_size + // assertions
             (4 + usize::from(ephemeral_signers_length)) +   // ephemeral_signers_bumps vec
-            message_size, // message
-        )
+            1 +   // next_instruction_index
+            4 + skipped_instructions_len(num_instructions) + // skipped_instructions
+            1 +   // capture_return_data
+            4 +   // return_data, grown during execution
+            4 +   // compute_units, grown during execution
+            1 +   // pending_message_hash, cleared once the message is written
+            1 + 32 + // batch
+            message_size; // message
+
+        require!(
+            size <= MAX_PERMITTED_DATA_LENGTH as usize,
+            SuperTxnError::TransactionTooLarge
+        );
+
+        Ok(size)
+    }
+
+    /// Size of the account data holding a header of `header_len` bytes followed by a message
+    /// of `message_len` bytes. Room is kept for every vote the approval policy can still record,
+    /// so votes always fit in the account.
+    pub fn account_size(&self, header_len: usize, message_len: usize) -> usize {
+        let reserved_votes_size = self
+            .approval
+            .as_ref()
+            .map_or(0, ApprovalPolicy::unused_size);
+        header_len + reserved_votes_size + message_len
+    }
+
+    /// Checks that `lamports` held by an account of `data_len` bytes cover both
+    /// the escrowed bounty and the rent exemption.
+    pub fn check_bounty_escrow(&self, lamports: u64, data_len: usize) -> Result<()> {
+        let rent_lamports = lamports
+            .checked_sub(self.bounty_lamports)
+            .ok_or(SuperTxnError::BountyNotCovered)?;
+        require!(
+            rent_lamports >= rent_exempt_lamports(data_len)?,
+            SuperTxnError::BountyNotCovered
+        );
+        Ok(())
+    }
+
+    /// Checks that the transaction can be executed at `clock`.
+    pub fn check_validity(&self, clock: &Clock) -> Result<()> {
+        if let Some(valid_after) = &self.valid_after {
+            require!(
+                !valid_after.is_after(clock),
+                SuperTxnError::TransactionNotYetValid
+            );
+        }
+        require!(!self.is_expired(clock), SuperTxnError::TransactionExpired);
+        Ok(())
+    }
+
+    /// Whether `valid_until` has passed at `clock`.
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        self.valid_until
+            .as_ref()
+            .is_some_and(|valid_until| valid_until.is_before(clock))
+    }
+
+    /// Sets the message and sizes the skipped instructions bitmap to it.
+    pub fn set_message(&mut self, message: SuperTransactionMessage) -> Result<()> {
+        self.skipped_instructions = vec![0; skipped_instructions_len(message.instructions.len())];
+        self.validate_assertions(&message)?;
+        self.message = message;
+        Ok(())
+    }
+
+    /// Completes a pending transaction with `message`, which is written in place after the
+    /// header rather than set on `self.message`, and sizes the skipped instructions bitmap to it.
+    pub fn complete_pending(&mut self, message: &impl TransactionMessageView) -> Result<()> {
+        self.pending_message_hash = None;
+        self.skipped_instructions = vec![0; skipped_instructions_len(message.num_instructions())];
+        self.validate_assertions(message)
+    }
+
+    /// Marks the instruction at `index` as skipped.
+    pub fn mark_skipped(&mut self, index: usize) {
+        self.skipped_instructions[index / 8] |= 1 << (index % 8);
+    }
+
+    /// Whether the instruction at `index` was skipped.
+    pub fn is_skipped(&self, index: usize) -> bool {
+        self.skipped_instructions[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Checks that every assertion references an account of `message`.
+    /// Pending transactions are checked once their message is written.
+    pub fn validate_assertions(&self, message: &impl TransactionMessageView) -> Result<()> {
+        if self.is_pending() {
+            return Ok(());
+        }
+        let num_all_account_keys = message.num_all_account_keys();
+        for assertion in &self.assertions {
+            require!(
+                usize::from(assertion.account_index()) < num_all_account_keys,
+                SuperTxnError::InvalidInstructionArgs
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether the transaction was created from a buffer too large to be written
+    /// in a single instruction and is still waiting for `super_transaction_realloc`.
+    pub fn is_pending(&self) -> bool {
+        self.pending_message_hash.is_some()
     }
     /// Reduces the SuperTransaction to its default empty value and moves
     /// ownership of the data to the caller/return value.
     pub fn take(&mut self) -> SuperTransaction {
         core::mem::take(self)
     }
+
+    /// Deserializes the account data except for `message`, which is serialized last,
+    /// leaving `buf` at the start of the message so it can be borrowed with
+    /// `SuperTransactionMessageRef` instead of being copied on the heap.
+    pub fn try_deserialize_header(buf: &mut &[u8]) -> Result<Self> {
+        require!(
+            buf.len() >= 8,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound
+        );
+        require!(
+            buf[..8] == Self::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        *buf = &buf[8..];
+
+        // Fields are deserialized in declaration order.
+        Ok(Self {
+            creator: AnchorDeserialize::deserialize(buf)?,
+            index: AnchorDeserialize::deserialize(buf)?,
+            bump: AnchorDeserialize::deserialize(buf)?,
+            created_at_slot: AnchorDeserialize::deserialize(buf)?,
+            created_at_unix: AnchorDeserialize::deserialize(buf)?,
+            memo: AnchorDeserialize::deserialize(buf)?,
+            approval: AnchorDeserialize::deserialize(buf)?,
+            executor: AnchorDeserialize::deserialize(buf)?,
+            bounty_lamports: AnchorDeserialize::deserialize(buf)?,
+            valid_after: AnchorDeserialize::deserialize(buf)?,
+            valid_until: AnchorDeserialize::deserialize(buf)?,
+            assertions: AnchorDeserialize::deserialize(buf)?,
+            ephemeral_signer_bumps: AnchorDeserialize::deserialize(buf)?,
+            next_instruction_index: AnchorDeserialize::deserialize(buf)?,
+            skipped_instructions: AnchorDeserialize::deserialize(buf)?,
+            capture_return_data: AnchorDeserialize::deserialize(buf)?,
+            return_data: AnchorDeserialize::deserialize(buf)?,
+            compute_units: AnchorDeserialize::deserialize(buf)?,
+            pending_message_hash: AnchorDeserialize::deserialize(buf)?,
+            batch: AnchorDeserialize::deserialize(buf)?,
+            message: SuperTransactionMessage::default(),
+        })
+    }
+
+    /// Serializes the discriminator and every field but `message`,
+    /// the counterpart of `try_deserialize_header`.
+    pub fn try_serialize_header<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        let Self {
+            creator,
+            index,
+            bump,
+            created_at_slot,
+            created_at_unix,
+            memo,
+            approval,
+            executor,
+            bounty_lamports,
+            valid_after,
+            valid_until,
+            assertions,
+            ephemeral_signer_bumps,
+            next_instruction_index,
+            skipped_instructions,
+            capture_return_data,
+            return_data,
+            compute_units,
+            pending_message_hash,
+            batch,
+            message: _,
+        } = self;
+
+        writer.write_all(&Self::DISCRIMINATOR)?;
+        creator.serialize(writer)?;
+        index.serialize(writer)?;
+        bump.serialize(writer)?;
+        created_at_slot.serialize(writer)?;
+        created_at_unix.serialize(writer)?;
+        memo.serialize(writer)?;
+        approval.serialize(writer)?;
+        executor.serialize(writer)?;
+        bounty_lamports.serialize(writer)?;
+        valid_after.serialize(writer)?;
+        valid_until.serialize(writer)?;
+        assertions.serialize(writer)?;
+        ephemeral_signer_bumps.serialize(writer)?;
+        next_instruction_index.serialize(writer)?;
+        skipped_instructions.serialize(writer)?;
+        capture_return_data.serialize(writer)?;
+        return_data.serialize(writer)?;
+        compute_units.serialize(writer)?;
+        pending_message_hash.serialize(writer)?;
+        batch.serialize(writer)?;
+        Ok(())
+    }
+}
+
+/// Return data set by a wrapped instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionReturnData {
+    /// Index of the instruction in the message.
+    pub index: u8,
+    /// Program that set the return data.
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,
+}
+
+/// Compute units consumed by a wrapped instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstructionComputeUnits {
+    /// Index of the instruction in the message.
+    pub index: u8,
+    pub units: u32,
+}
+
+/// Length of the skipped instructions bitmap of a message with `num_instructions` instructions.
+pub fn skipped_instructions_len(num_instructions: usize) -> usize {
+    num_instructions.div_ceil(8)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum Executor {
+    /// Only the creator can execute the transaction.
+    #[default]
+    Creator,
+    /// The creator or the delegate can execute the transaction.
+    Delegate(Pubkey),
+    /// Anyone can execute the transaction.
+    Anyone,
+}
+
+impl Executor {
+    /// Whether `executor` is allowed to execute a transaction of `creator`.
+    pub fn can_execute(&self, creator: &Pubkey, executor: &Pubkey) -> bool {
+        match self {
+            Executor::Creator => executor == creator,
+            Executor::Delegate(delegate) => executor == creator || executor == delegate,
+            Executor::Anyone => true,
+        }
+    }
+}
+
+/// Bound of the validity window of a `SuperTransaction`, inclusive.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidityBound {
+    Slot(u64),
+    UnixTimestamp(i64),
+}
+
+impl ValidityBound {
+    /// Whether the bound is strictly after `clock`.
+    pub fn is_after(&self, clock: &Clock) -> bool {
+        match self {
+            ValidityBound::Slot(slot) => *slot > clock.slot,
+            ValidityBound::UnixTimestamp(timestamp) => *timestamp > clock.unix_timestamp,
+        }
+    }
+
+    /// Whether the bound is strictly before `clock`.
+    pub fn is_before(&self, clock: &Clock) -> bool {
+        match self {
+            ValidityBound::Slot(slot) => *slot < clock.slot,
+            ValidityBound::UnixTimestamp(timestamp) => *timestamp < clock.unix_timestamp,
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -73,51 +407,6 @@ pub struct SuperTransactionMessage {
     pub address_table_lookups: Vec<SuperMessageAddressTableLookup>,
 }
 
-impl SuperTransactionMessage {
-    /// Returns the number of all the account keys (static + dynamic) in the message.
-    pub fn num_all_account_keys(&self) -> usize {
-        let num_account_keys_from_lookups = self
-            .address_table_lookups
-            .iter()
-            .map(|lookup| lookup.writable_indexes.len() + lookup.readonly_indexes.len())
-            .sum::<usize>();
-
-        self.account_keys.len() + num_account_keys_from_lookups
-    }
-
-    /// Returns true if the account at the specified index is a part of static `account_keys` and was requested to be writable.
-    pub fn is_static_writable_index(&self, key_index: usize) -> bool {
-        let num_account_keys = self.account_keys.len();
-        let num_signers = usize::from(self.num_signers);
-        let num_writable_signers = usize::from(self.num_writable_signers);
-        let num_writable_non_signers = usize::from(self.num_writable_non_signers);
-
-        if key_index >= num_account_keys {
-            // `index` is not a part of static `account_keys`.
-            return false;
-        }
-
-        if key_index < num_writable_signers {
-            // `index` is within the range of writable signer keys.
-            return true;
-        }
-
-        if key_index >= num_signers {
-            // `index` is within the range of non-signer keys.
-            let index_into_non_signers = key_index.saturating_sub(num_signers);
-            // Whether `index` is within the range of writable non-signer keys.
-            return index_into_non_signers < num_writable_non_signers;
-        }
-
-        false
-    }
-
-    /// Returns true if the account at the specified index was requested to be a signer.
-    pub fn is_signer_index(&self, key_index: usize) -> bool {
-        key_index < usize::from(self.num_signers)
-    }
-}
-
 impl TryFrom<TransactionMessage> for SuperTransactionMessage {
     type Error = Error;
 
@@ -224,3 +513,269 @@ impl From<MessageAddressTableLookup> for SuperMessageAddressTableLookup {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state::{ApprovalStatus, SuperTransactionMessageRef, Vote};
+
+    fn transaction_message() -> TransactionMessage {
+        TransactionMessage {
+            num_signers: 1,
+            num_writable_signers: 1,
+            num_writable_non_signers: 1,
+            account_keys: vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ]
+            .into(),
+            instructions: vec![CompiledInstruction {
+                program_id_index: 2,
+                account_indexes: vec![0, 1].into(),
+                data: vec![2, 0, 0, 0, 64, 66, 15, 0, 0, 0, 0, 0].into(),
+            }]
+            .into(),
+            address_table_lookups: vec![MessageAddressTableLookup {
+                account_key: Pubkey::new_unique(),
+                writable_indexes: vec![3].into(),
+                readonly_indexes: vec![].into(),
+            }]
+            .into(),
+        }
+    }
+
+    #[test]
+    fn test_size_matches_serialized_len() {
+        let message = transaction_message();
+        let message_bytes = message.try_to_vec().unwrap();
+
+        let assertions = vec![Assertion::Data {
+            account_index: 1,
+            offset: 8,
+            data: vec![1, 2, 3],
+        }];
+
+        for memo in [None, Some("memo".to_string())] {
+            let mut transaction = SuperTransaction {
+                creator: Pubkey::new_unique(),
+                memo: memo.clone(),
+                executor: Executor::Delegate(Pubkey::new_unique()),
+                valid_after: Some(ValidityBound::Slot(1)),
+                valid_until: Some(ValidityBound::UnixTimestamp(2)),
+                assertions: assertions.clone(),
+                ephemeral_signer_bumps: vec![255, 254],
+                capture_return_data: true,
+                batch: Some(Pubkey::new_unique()),
+                ..Default::default()
+            };
+            transaction
+                .set_message(message.clone().try_into().unwrap())
+                .unwrap();
+
+            assert_eq!(
+                SuperTransaction::size(2, &message_bytes, memo.as_deref(), None, &assertions)
+                    .unwrap(),
+                8 + transaction.try_to_vec().unwrap().len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_account_size_keeps_room_for_votes() {
+        let message = transaction_message();
+        let message_bytes = message.try_to_vec().unwrap();
+        let members: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+        let mut transaction = SuperTransaction {
+            creator: Pubkey::new_unique(),
+            approval: Some(ApprovalPolicy::new(members.clone(), 2, 0).unwrap()),
+            capture_return_data: true,
+            ..Default::default()
+        };
+        transaction
+            .set_message(message.try_into().unwrap())
+            .unwrap();
+        let message_len = transaction.message.try_to_vec().unwrap().len();
+        let account_size = |transaction: &SuperTransaction| {
+            let mut header = Vec::new();
+            transaction.try_serialize_header(&mut header).unwrap();
+            transaction.account_size(header.len(), message_len)
+        };
+        assert!(
+            account_size(&transaction)
+                <= SuperTransaction::size(0, &message_bytes, None, Some(members.len()), &[])
+                    .unwrap()
+        );
+
+        let approval = transaction.approval.as_mut().unwrap();
+        approval.vote(members[0], Vote::Approve, 1).unwrap();
+        approval.vote(members[1], Vote::Approve, 1).unwrap();
+
+        // Captured return data grows the account, the votes still fit once it is stored.
+        transaction.return_data.push(InstructionReturnData {
+            index: 0,
+            program_id: Pubkey::new_unique(),
+            data: vec![1; 100],
+        });
+        let size = account_size(&transaction);
+
+        let approval = transaction.approval.as_mut().unwrap();
+        approval.vote(members[0], Vote::Cancel, 2).unwrap();
+        approval.vote(members[1], Vote::Cancel, 2).unwrap();
+        assert!(matches!(approval.status, ApprovalStatus::Cancelled { .. }));
+        assert!(transaction.try_to_vec().unwrap().len() + 8 <= size);
+        assert_eq!(account_size(&transaction), size);
+    }
+
+    #[test]
+    fn test_message_size_validates_like_try_from() {
+        let mut message = transaction_message();
+        let message_bytes = message.try_to_vec().unwrap();
+        let super_message: SuperTransactionMessage = message.clone().try_into().unwrap();
+        assert_eq!(
+            super_transaction_message_size(&message_bytes).unwrap(),
+            (super_message.try_to_vec().unwrap().len(), 1)
+        );
+        assert!(super_transaction_message_size(&message_bytes[..message_bytes.len() - 1]).is_err());
+
+        // Index past the static and looked up account keys.
+        message.instructions = vec![CompiledInstruction {
+            program_id_index: 4,
+            account_indexes: vec![].into(),
+            data: vec![].into(),
+        }]
+        .into();
+        assert!(SuperTransactionMessage::try_from(message.clone()).is_err());
+        assert!(super_transaction_message_size(&message.try_to_vec().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_message_converted_in_place() {
+        let message = transaction_message();
+        let message_bytes = message.try_to_vec().unwrap();
+        let super_message_bytes = SuperTransactionMessage::try_from(message.clone())
+            .unwrap()
+            .try_to_vec()
+            .unwrap();
+
+        let message_ref =
+            SuperTransactionMessageRef::from_transaction_message(&message_bytes).unwrap();
+        let mut data = vec![0; super_message_bytes.len()];
+        message_ref.serialize(&mut data.as_mut_slice()).unwrap();
+        assert_eq!(data, super_message_bytes);
+        assert!(message_ref.serialize(&mut &mut data[1..]).is_err());
+
+        let mut transaction = SuperTransaction {
+            pending_message_hash: Some([1; 32]),
+            ..Default::default()
+        };
+        transaction.complete_pending(&message_ref).unwrap();
+        assert!(!transaction.is_pending());
+        assert_eq!(transaction.skipped_instructions, vec![0]);
+    }
+
+    #[test]
+    fn test_header_round_trip() {
+        let mut transaction = SuperTransaction {
+            creator: Pubkey::new_unique(),
+            memo: Some("memo".to_string()),
+            bounty_lamports: 42,
+            ephemeral_signer_bumps: vec![255],
+            capture_return_data: true,
+            compute_units: vec![InstructionComputeUnits {
+                index: 0,
+                units: 1000,
+            }],
+            pending_message_hash: Some([7; 32]),
+            batch: Some(Pubkey::new_unique()),
+            ..Default::default()
+        };
+        transaction
+            .set_message(transaction_message().try_into().unwrap())
+            .unwrap();
+        let mut data = Vec::new();
+        transaction.try_serialize(&mut data).unwrap();
+
+        let mut header = Vec::new();
+        transaction.try_serialize_header(&mut header).unwrap();
+        assert_eq!(header, data[..header.len()]);
+
+        let mut buf = data.as_slice();
+        let deserialized = SuperTransaction::try_deserialize_header(&mut buf).unwrap();
+        assert_eq!(buf, transaction.message.try_to_vec().unwrap());
+        assert_eq!(deserialized.creator, transaction.creator);
+        assert_eq!(deserialized.memo, transaction.memo);
+        assert_eq!(deserialized.bounty_lamports, 42);
+        assert_eq!(deserialized.compute_units, transaction.compute_units);
+        assert_eq!(deserialized.pending_message_hash, Some([7; 32]));
+        assert_eq!(deserialized.batch, transaction.batch);
+        assert_eq!(
+            deserialized.skipped_instructions,
+            transaction.skipped_instructions
+        );
+
+        let message = SuperTransactionMessageRef::deserialize(&mut buf).unwrap();
+        assert!(buf.is_empty());
+        assert_eq!(message.num_instructions(), 1);
+    }
+
+    #[test]
+    fn test_executor_can_execute() {
+        let creator = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        assert!(Executor::Creator.can_execute(&creator, &creator));
+        assert!(!Executor::Creator.can_execute(&creator, &delegate));
+        assert!(Executor::Delegate(delegate).can_execute(&creator, &creator));
+        assert!(Executor::Delegate(delegate).can_execute(&creator, &delegate));
+        assert!(!Executor::Delegate(delegate).can_execute(&creator, &other));
+        assert!(Executor::Anyone.can_execute(&creator, &other));
+    }
+
+    #[test]
+    fn test_validity_window() {
+        let clock = |slot, unix_timestamp| Clock {
+            slot,
+            unix_timestamp,
+            ..Default::default()
+        };
+        let transaction = SuperTransaction {
+            valid_after: Some(ValidityBound::Slot(10)),
+            valid_until: Some(ValidityBound::UnixTimestamp(100)),
+            ..Default::default()
+        };
+
+        assert!(transaction.check_validity(&clock(9, 50)).is_err());
+        assert!(transaction.check_validity(&clock(10, 50)).is_ok());
+        assert!(transaction.check_validity(&clock(20, 100)).is_ok());
+        assert!(!transaction.is_expired(&clock(20, 100)));
+        assert!(transaction.check_validity(&clock(20, 101)).is_err());
+        assert!(transaction.is_expired(&clock(20, 101)));
+        assert!(!SuperTransaction::default().is_expired(&clock(u64::MAX, i64::MAX)));
+    }
+
+    #[test]
+    fn test_skipped_instructions() {
+        let mut transaction = SuperTransaction::default();
+        let mut message: SuperTransactionMessage = transaction_message().try_into().unwrap();
+        message.instructions = vec![message.instructions[0].clone(); 9];
+        transaction.set_message(message).unwrap();
+        assert_eq!(transaction.skipped_instructions, vec![0, 0]);
+
+        transaction.mark_skipped(1);
+        transaction.mark_skipped(8);
+        assert_eq!(transaction.skipped_instructions, vec![0b10, 0b1]);
+        assert!(transaction.is_skipped(8));
+        assert!(!transaction.is_skipped(0));
+    }
+
+    #[test]
+    fn test_size_rejects_long_memo() {
+        let message_bytes = transaction_message().try_to_vec().unwrap();
+        let memo = "a".repeat(MAX_MEMO_LEN + 1);
+
+        assert!(SuperTransaction::size(0, &message_bytes, Some(&memo), None, &[]).is_err());
+    }
+}
@@ -1,11 +1,19 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::system_instruction::MAX_PERMITTED_DATA_LENGTH;
+use anchor_lang::Discriminator;
 
 use crate::errors::SuperTxnError;
 
-// Maximum PDA allocation size in an inner ix is 10240 bytes.
-// 10240 - account contents = 10128 bytes
-pub const MAX_BUFFER_SIZE: usize = 10128 ;
+// Maximum account size is 10 MiB. Buffers are allocated up to 10240 bytes at `init`
+// and then grown in 10240 byte steps with `txn_buffer_realloc`.
+// 10 MiB - 4 KiB leaves room for the account contents and the chunk bitmap.
+pub const MAX_BUFFER_SIZE: usize = MAX_PERMITTED_DATA_LENGTH as usize - 4096;
+
+// Granularity at which written byte ranges of the buffer are tracked.
+// Random-access writes must start on a chunk boundary.
+pub const TRANSACTION_BUFFER_CHUNK_SIZE: usize = 512;
 
 #[account]
 #[derive(Default, Debug)]
@@ -17,13 +25,18 @@ pub struct TransactionBuffer {
     /// Hash of the final assembled transaction message.
     pub final_buffer_hash: [u8; 32],
     /// The size of the final assembled transaction message.
-    pub final_buffer_size: u16,
+    pub final_buffer_size: u32,
+    /// Optional Merkle root over the `TRANSACTION_BUFFER_CHUNK_SIZE` chunks of the final buffer.
+    /// When set, every chunk must come with a proof and is verified as it is written.
+    pub chunk_merkle_root: Option<[u8; 32]>,
     /// The buffer of the transaction message.
     pub buffer: Vec<u8>,
+    /// Bitmap of the `TRANSACTION_BUFFER_CHUNK_SIZE` chunks of `buffer` that have been written.
+    pub written_chunks: Vec<u8>,
 }
 
 impl TransactionBuffer {
-    pub fn size(final_message_buffer_size: u16) -> Result<usize> {
+    pub fn size(final_message_buffer_size: u32) -> Result<usize> {
         // Make sure final size is not greater than MAX_BUFFER_SIZE bytes.
         if (final_message_buffer_size as usize) > MAX_BUFFER_SIZE {
             return err!(SuperTxnError::FinalBufferSizeExceeded);
@@ -33,26 +46,151 @@ impl TransactionBuffer {
             32 +  // creator
             1 +   // buffer_index
             32 +  // transaction_message_hash
-            2 +  // final_buffer_size
+            4 +  // final_buffer_size
+            1 + 32 + // chunk_merkle_root
+            4 + // vec length bytes
+            final_message_buffer_size as usize + // buffer
             4 + // vec length bytes
-            final_message_buffer_size as usize, // buffer
+            Self::written_chunks_len(final_message_buffer_size), // written_chunks
         )
     }
 
-    pub fn validate_hash(&self) -> Result<()> {
-        let message_buffer_hash = hash(&self.buffer);
+    /// New buffer holding `buffer` as the first slice of the final buffer.
+    pub fn new(
+        creator: Pubkey,
+        buffer_index: u8,
+        final_buffer_hash: [u8; 32],
+        final_buffer_size: u32,
+        chunk_merkle_root: Option<[u8; 32]>,
+        buffer: &[u8],
+    ) -> Result<Self> {
+        let mut transaction_buffer = Self {
+            creator,
+            buffer_index,
+            final_buffer_hash,
+            final_buffer_size,
+            chunk_merkle_root,
+            buffer: Vec::new(),
+            written_chunks: vec![0; Self::written_chunks_len(final_buffer_size)],
+        };
+
+        // Chunks of a Merkle-committed buffer can only be written with a proof,
+        // the buffer is then created empty and filled with `txn_buffer_write_at`.
+        if chunk_merkle_root.is_some() {
+            require!(buffer.is_empty(), SuperTxnError::InvalidInstructionArgs);
+        }
+        if !buffer.is_empty() {
+            transaction_buffer.extend(buffer, None)?;
+        }
+
+        // Invariant function on the transaction buffer
+        transaction_buffer.invariant()?;
+
+        Ok(transaction_buffer)
+    }
+
+    /// Size of the account at `init`, capped by the inner instruction allocation limit.
+    pub fn initial_size(final_message_buffer_size: u32) -> Result<usize> {
+        Ok(Self::size(final_message_buffer_size)?.min(MAX_PERMITTED_DATA_INCREASE))
+    }
+
+    /// Size of the account after growing it by one realloc step from `current_size`.
+    pub fn next_size(&self, current_size: usize) -> Result<usize> {
+        Ok(Self::size(self.final_buffer_size)?
+            .min(current_size.saturating_add(MAX_PERMITTED_DATA_INCREASE)))
+    }
+
+    /// Number of chunks the final buffer is split into.
+    pub fn num_chunks(final_buffer_size: u32) -> usize {
+        (final_buffer_size as usize).div_ceil(TRANSACTION_BUFFER_CHUNK_SIZE)
+    }
+
+    /// Length in bytes of the `written_chunks` bitmap.
+    pub fn written_chunks_len(final_buffer_size: u32) -> usize {
+        Self::num_chunks(final_buffer_size).div_ceil(8)
+    }
+
+    /// Marks every chunk that lies entirely within `start..end` as written.
+    /// The last chunk may be shorter than `TRANSACTION_BUFFER_CHUNK_SIZE`.
+    pub fn mark_chunks_written(&mut self, start: usize, end: usize) {
+        let final_buffer_size = self.final_buffer_size as usize;
+        let first_chunk = start.div_ceil(TRANSACTION_BUFFER_CHUNK_SIZE);
+
+        for chunk_index in first_chunk..Self::num_chunks(self.final_buffer_size) {
+            let chunk_start = chunk_index * TRANSACTION_BUFFER_CHUNK_SIZE;
+            let chunk_end = (chunk_start + TRANSACTION_BUFFER_CHUNK_SIZE).min(final_buffer_size);
+            if chunk_end > end {
+                break;
+            }
+            self.written_chunks[chunk_index / 8] |= 1 << (chunk_index % 8);
+        }
+    }
+
+    /// Depth of the chunk Merkle tree, i.e. the length of every chunk proof.
+    pub fn chunk_proof_len(final_buffer_size: u32) -> usize {
+        Self::num_chunks(final_buffer_size)
+            .next_power_of_two()
+            .trailing_zeros() as usize
+    }
+
+    /// Checks `bytes` written at `offset` against `chunk_merkle_root`, if one was committed to.
+    ///
+    /// Leaves are `hashv([0x00, chunk_index as u32 LE, chunk])` and nodes are
+    /// `hashv([0x01, left, right])`. The tree is padded with zeroed leaves up to
+    /// the next power of two, so every proof has `chunk_proof_len` siblings.
+    pub fn verify_chunk(
+        &self,
+        offset: usize,
+        bytes: &[u8],
+        chunk_proof: Option<&[[u8; 32]]>,
+    ) -> Result<()> {
+        let Some(chunk_merkle_root) = self.chunk_merkle_root else {
+            return Ok(());
+        };
+
+        let chunk_index = offset / TRANSACTION_BUFFER_CHUNK_SIZE;
+        let chunk_len = TRANSACTION_BUFFER_CHUNK_SIZE
+            .min((self.final_buffer_size as usize).saturating_sub(offset));
+
+        // Chunks must be written one at a time, each with its own proof.
         require!(
-            message_buffer_hash.to_bytes() == self.final_buffer_hash,
-            SuperTxnError::FinalBufferHashMismatch
+            offset % TRANSACTION_BUFFER_CHUNK_SIZE == 0 && bytes.len() == chunk_len,
+            SuperTxnError::UnalignedBufferWrite
         );
+
+        let verified = chunk_proof.is_some_and(|chunk_proof| {
+            chunk_proof.len() == Self::chunk_proof_len(self.final_buffer_size)
+                && compute_chunk_merkle_root(chunk_index as u32, bytes, chunk_proof)
+                    == chunk_merkle_root
+        });
+        if !verified {
+            msg!("Chunk {} failed Merkle proof verification", chunk_index);
+            return err!(SuperTxnError::ChunkProofMismatch);
+        }
+
         Ok(())
     }
-    pub fn validate_size(&self) -> Result<()> {
-        require_eq!(
+
+    /// Appends `bytes` to the end of the buffer.
+    pub fn extend(&mut self, bytes: &[u8], chunk_proof: Option<&[[u8; 32]]>) -> Result<()> {
+        let start = self.buffer.len();
+        self.verify_chunk(start, bytes, chunk_proof)?;
+
+        self.buffer.extend_from_slice(bytes);
+        self.mark_chunks_written(
+            start - start % TRANSACTION_BUFFER_CHUNK_SIZE,
             self.buffer.len(),
-            self.final_buffer_size as usize,
-            SuperTxnError::FinalBufferSizeMismatch
         );
+
+        Ok(())
+    }
+
+    pub fn validate_chunks(&self) -> Result<()> {
+        let num_chunks = Self::num_chunks(self.final_buffer_size);
+        let all_written = (0..num_chunks).all(|chunk_index| {
+            self.written_chunks[chunk_index / 8] & (1 << (chunk_index % 8)) != 0
+        });
+        require!(all_written, SuperTxnError::BufferChunksIncomplete);
         Ok(())
     }
 
@@ -69,7 +207,383 @@ impl TransactionBuffer {
             self.buffer.len() <= self.final_buffer_size as usize,
             SuperTxnError::FinalBufferSizeMismatch
         );
+        require_eq!(
+            self.written_chunks.len(),
+            Self::written_chunks_len(self.final_buffer_size),
+            SuperTxnError::FinalBufferSizeMismatch
+        );
+
+        Ok(())
+    }
+}
+
+/// Zero-copy view of a serialized `TransactionBuffer`.
+/// Only the header fields and the `written_chunks` bitmap are deserialized, the buffer
+/// bytes are read and written in place so large buffers are never copied on the heap.
+pub struct TransactionBufferRef<D> {
+    /// The deserialized fields, `buffer` is always empty.
+    pub header: TransactionBuffer,
+    /// Length of the buffer in the account data.
+    buffer_len: usize,
+    data: D,
+}
+
+impl<D: AsRef<[u8]>> TransactionBufferRef<D> {
+    /// Reads the fields around the buffer from the account data of a `TransactionBuffer`.
+    pub fn load(data: D) -> Result<Self> {
+        let mut buf = data.as_ref();
+        require!(
+            buf.len() >= 8,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound
+        );
+        require!(
+            buf[..8] == TransactionBuffer::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        buf = &buf[8..];
+
+        let creator = Pubkey::deserialize(&mut buf)?;
+        let buffer_index = u8::deserialize(&mut buf)?;
+        let final_buffer_hash = <[u8; 32]>::deserialize(&mut buf)?;
+        let final_buffer_size = u32::deserialize(&mut buf)?;
+        let chunk_merkle_root = Option::<[u8; 32]>::deserialize(&mut buf)?;
+        let buffer_len = u32::deserialize(&mut buf)? as usize;
+        require!(
+            buffer_len <= buf.len(),
+            anchor_lang::error::ErrorCode::AccountDidNotDeserialize
+        );
+        buf = &buf[buffer_len..];
+        let written_chunks = Vec::<u8>::deserialize(&mut buf)?;
+
+        let header = TransactionBuffer {
+            creator,
+            buffer_index,
+            final_buffer_hash,
+            final_buffer_size,
+            chunk_merkle_root,
+            buffer: Vec::new(),
+            written_chunks,
+        };
+        header.invariant()?;
+        require!(
+            buffer_len <= final_buffer_size as usize,
+            SuperTxnError::FinalBufferSizeMismatch
+        );
+
+        Ok(Self {
+            header,
+            buffer_len,
+            data,
+        })
+    }
+
+    /// Offset of the buffer bytes in the account data.
+    fn buffer_start(&self) -> usize {
+        8 + // discriminator
+        32 + // creator
+        1 + // buffer_index
+        32 + // final_buffer_hash
+        4 + // final_buffer_size
+        1 + self.header.chunk_merkle_root.map_or(0, |_| 32) + // chunk_merkle_root
+        4 // vec length bytes
+    }
+
+    /// The buffer bytes written so far.
+    pub fn buffer(&self) -> &[u8] {
+        let start = self.buffer_start();
+        &self.data.as_ref()[start..start + self.buffer_len]
+    }
+
+    /// Validates that the buffer is completely and correctly uploaded.
+    pub fn validate_finalized(&self) -> Result<()> {
+        // Validate that every chunk of the buffer has been written
+        self.header.validate_chunks()?;
+
+        // Validate that the final hash matches the buffer
+        require!(
+            hash(self.buffer()).to_bytes() == self.header.final_buffer_hash,
+            SuperTxnError::FinalBufferHashMismatch
+        );
+
+        // Validate that the final size is correct
+        require_eq!(
+            self.buffer_len,
+            self.header.final_buffer_size as usize,
+            SuperTxnError::FinalBufferSizeMismatch
+        );
+        Ok(())
+    }
+}
+
+impl<D: AsRef<[u8]> + AsMut<[u8]>> TransactionBufferRef<D> {
+    /// Writes `bytes` at `offset`, preallocating the whole buffer on first use.
+    pub fn write_at(
+        &mut self,
+        offset: usize,
+        bytes: &[u8],
+        chunk_proof: Option<&[[u8; 32]]>,
+    ) -> Result<()> {
+        let final_buffer_size = self.header.final_buffer_size as usize;
+        let end = offset
+            .checked_add(bytes.len())
+            .ok_or(SuperTxnError::FinalBufferSizeExceeded)?;
+
+        require!(
+            offset % TRANSACTION_BUFFER_CHUNK_SIZE == 0,
+            SuperTxnError::UnalignedBufferWrite
+        );
+        require!(
+            end <= final_buffer_size,
+            SuperTxnError::FinalBufferSizeExceeded
+        );
+        // Only the last chunk is allowed to be partially covered by a write.
+        require!(
+            bytes.len() % TRANSACTION_BUFFER_CHUNK_SIZE == 0 || end == final_buffer_size,
+            SuperTxnError::UnalignedBufferWrite
+        );
+
+        self.header.verify_chunk(offset, bytes, chunk_proof)?;
+
+        if self.buffer_len < final_buffer_size {
+            self.resize(final_buffer_size)?;
+        }
+        let start = self.buffer_start();
+        self.data.as_mut()[start + offset..start + end].copy_from_slice(bytes);
+        self.header.mark_chunks_written(offset, end);
+
+        self.store()
+    }
+
+    /// Appends `bytes` to the end of the buffer.
+    pub fn extend(&mut self, bytes: &[u8], chunk_proof: Option<&[[u8; 32]]>) -> Result<()> {
+        let offset = self.buffer_len;
+        let end = offset + bytes.len();
+        require!(
+            end <= self.header.final_buffer_size as usize,
+            SuperTxnError::FinalBufferSizeExceeded
+        );
+
+        self.header.verify_chunk(offset, bytes, chunk_proof)?;
+
+        self.resize(end)?;
+        let start = self.buffer_start();
+        self.data.as_mut()[start + offset..start + end].copy_from_slice(bytes);
+        self.header
+            .mark_chunks_written(offset - offset % TRANSACTION_BUFFER_CHUNK_SIZE, end);
+
+        self.store()
+    }
+
+    /// Grows the buffer to `len` zeroed bytes. Large buffers have to be grown
+    /// with `txn_buffer_realloc` before the account can hold them.
+    fn resize(&mut self, len: usize) -> Result<()> {
+        let start = self.buffer_start();
+        require!(
+            start + len + 4 + self.header.written_chunks.len() <= self.data.as_ref().len(),
+            SuperTxnError::AccountNotLargeEnough
+        );
+        self.data.as_mut()[start + self.buffer_len..start + len].fill(0);
+        self.buffer_len = len;
+        Ok(())
+    }
 
+    /// Writes the buffer length and the `written_chunks` bitmap that follows the buffer.
+    fn store(&mut self) -> Result<()> {
+        let start = self.buffer_start();
+        let data = self.data.as_mut();
+        data[start - 4..start].copy_from_slice(&(self.buffer_len as u32).to_le_bytes());
+        self.header
+            .written_chunks
+            .serialize(&mut &mut data[start + self.buffer_len..])?;
         Ok(())
     }
 }
+
+/// Folds `chunk_proof` into the leaf of `chunk` at `chunk_index` and returns the resulting root.
+pub fn compute_chunk_merkle_root(
+    chunk_index: u32,
+    chunk: &[u8],
+    chunk_proof: &[[u8; 32]],
+) -> [u8; 32] {
+    let mut node = hashv(&[&[0x00], &chunk_index.to_le_bytes(), chunk]).to_bytes();
+    let mut index = chunk_index;
+    for sibling in chunk_proof {
+        node = if index & 1 == 0 {
+            hashv(&[&[0x01], &node, sibling]).to_bytes()
+        } else {
+            hashv(&[&[0x01], sibling, &node]).to_bytes()
+        };
+        index >>= 1;
+    }
+    node
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn buffer(final_buffer_size: u32) -> TransactionBuffer {
+        TransactionBuffer {
+            final_buffer_size,
+            written_chunks: vec![0; TransactionBuffer::written_chunks_len(final_buffer_size)],
+            ..Default::default()
+        }
+    }
+
+    fn buffer_ref(
+        transaction_buffer: TransactionBuffer,
+        data_len: usize,
+    ) -> TransactionBufferRef<Vec<u8>> {
+        let mut data = Vec::new();
+        transaction_buffer.try_serialize(&mut data).unwrap();
+        data.resize(data_len, 0);
+        TransactionBufferRef::load(data).unwrap()
+    }
+
+    #[test]
+    fn test_extend_marks_chunks_once_complete() {
+        let mut transaction_buffer = buffer(1200);
+
+        transaction_buffer.extend(&[1; 300], None).unwrap();
+        assert!(transaction_buffer.validate_chunks().is_err());
+        assert_eq!(transaction_buffer.written_chunks, vec![0b000]);
+
+        transaction_buffer.extend(&[2; 300], None).unwrap();
+        assert_eq!(transaction_buffer.written_chunks, vec![0b001]);
+
+        transaction_buffer.extend(&[3; 600], None).unwrap();
+        assert_eq!(transaction_buffer.written_chunks, vec![0b111]);
+        assert!(transaction_buffer.validate_chunks().is_ok());
+    }
+
+    #[test]
+    fn test_buffer_ref_writes_in_place() {
+        // Room for 600 bytes of the buffer, without a `chunk_merkle_root`.
+        let initial_size = TransactionBuffer::size(1200).unwrap() - 32 - 600;
+        let mut transaction_buffer_ref = buffer_ref(buffer(1200), initial_size);
+
+        transaction_buffer_ref.extend(&[1; 300], None).unwrap();
+        transaction_buffer_ref.extend(&[2; 300], None).unwrap();
+        // The account has to be grown before the rest of the buffer fits.
+        assert!(transaction_buffer_ref.extend(&[3; 1], None).is_err());
+        assert!(transaction_buffer_ref
+            .write_at(1024, &[3; 176], None)
+            .is_err());
+
+        transaction_buffer_ref
+            .data
+            .resize(TransactionBuffer::size(1200).unwrap(), 0);
+        transaction_buffer_ref.extend(&[3; 424], None).unwrap();
+        transaction_buffer_ref
+            .write_at(1024, &[4; 176], None)
+            .unwrap();
+        assert!(transaction_buffer_ref.extend(&[5; 1], None).is_err());
+        assert!(transaction_buffer_ref.validate_finalized().is_err());
+
+        // The account data stays a valid borsh `TransactionBuffer`.
+        let transaction_buffer =
+            TransactionBuffer::try_deserialize(&mut transaction_buffer_ref.data.as_slice())
+                .unwrap();
+        assert_eq!(transaction_buffer.buffer, transaction_buffer_ref.buffer());
+        assert_eq!(transaction_buffer.written_chunks, vec![0b111]);
+        assert_eq!(&transaction_buffer.buffer[598..602], &[2, 2, 3, 3]);
+        assert_eq!(&transaction_buffer.buffer[1022..1026], &[3, 3, 4, 4]);
+
+        let transaction_buffer = TransactionBuffer {
+            final_buffer_hash: hash(&transaction_buffer.buffer).to_bytes(),
+            ..transaction_buffer
+        };
+        assert!(
+            buffer_ref(transaction_buffer, transaction_buffer_ref.data.len())
+                .validate_finalized()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_new_with_first_slice() {
+        let transaction_buffer =
+            TransactionBuffer::new(Pubkey::default(), 0, [0; 32], 1200, None, &[1; 600]).unwrap();
+        assert_eq!(transaction_buffer.written_chunks, vec![0b001]);
+
+        // Merkle-committed chunks can't be written without a proof.
+        let root = Some([1; 32]);
+        assert!(
+            TransactionBuffer::new(Pubkey::default(), 0, [0; 32], 1200, root, &[1; 512]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_write_at_out_of_order() {
+        let mut transaction_buffer =
+            buffer_ref(buffer(1200), TransactionBuffer::size(1200).unwrap());
+
+        transaction_buffer.write_at(1024, &[3; 176], None).unwrap();
+        assert_eq!(transaction_buffer.buffer().len(), 1200);
+        assert_eq!(transaction_buffer.header.written_chunks, vec![0b100]);
+
+        transaction_buffer.write_at(0, &[1; 512], None).unwrap();
+        assert!(transaction_buffer.header.validate_chunks().is_err());
+
+        transaction_buffer.write_at(512, &[2; 512], None).unwrap();
+        assert!(transaction_buffer.header.validate_chunks().is_ok());
+        assert_eq!(&transaction_buffer.buffer()[510..514], &[1, 1, 2, 2]);
+        assert_eq!(&transaction_buffer.buffer()[1022..1026], &[2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_write_at_rejects_unaligned_writes() {
+        let mut transaction_buffer =
+            buffer_ref(buffer(1200), TransactionBuffer::size(1200).unwrap());
+
+        assert!(transaction_buffer.write_at(100, &[1; 512], None).is_err());
+        assert!(transaction_buffer.write_at(0, &[1; 100], None).is_err());
+        assert!(transaction_buffer.write_at(1024, &[1; 177], None).is_err());
+    }
+
+    #[test]
+    fn test_chunk_merkle_proofs() {
+        let chunks: [&[u8]; 3] = [&[1; 512], &[2; 512], &[3; 176]];
+        let zero_leaf = [0; 32];
+        let leaves: Vec<[u8; 32]> = chunks
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| hashv(&[&[0x00], &(i as u32).to_le_bytes(), chunk]).to_bytes())
+            .collect();
+        let node = |left: &[u8; 32], right: &[u8; 32]| hashv(&[&[0x01], left, right]).to_bytes();
+        let left = node(&leaves[0], &leaves[1]);
+        let right = node(&leaves[2], &zero_leaf);
+        let root = node(&left, &right);
+
+        // Created empty by `txn_buffer_create`, the chunks are then written with their proofs.
+        let transaction_buffer =
+            TransactionBuffer::new(Pubkey::default(), 0, [0; 32], 1200, Some(root), &[]).unwrap();
+        let mut transaction_buffer =
+            buffer_ref(transaction_buffer, TransactionBuffer::size(1200).unwrap());
+        assert_eq!(TransactionBuffer::chunk_proof_len(1200), 2);
+
+        // Wrong proof, missing proof and mismatched chunk data are all rejected.
+        assert!(transaction_buffer
+            .write_at(1024, &[3; 176], Some(&[leaves[1], left]))
+            .is_err());
+        assert!(transaction_buffer.write_at(1024, &[3; 176], None).is_err());
+        assert!(transaction_buffer
+            .write_at(0, &[9; 512], Some(&[leaves[1], right]))
+            .is_err());
+        // Multi-chunk writes can't be verified against a single proof.
+        assert!(transaction_buffer
+            .write_at(0, &[1; 1024], Some(&[leaves[1], right]))
+            .is_err());
+
+        transaction_buffer
+            .write_at(1024, &[3; 176], Some(&[zero_leaf, left]))
+            .unwrap();
+        transaction_buffer
+            .write_at(0, &[1; 512], Some(&[leaves[1], right]))
+            .unwrap();
+        transaction_buffer
+            .write_at(512, &[2; 512], Some(&[leaves[0], right]))
+            .unwrap();
+        assert!(transaction_buffer.header.validate_chunks().is_ok());
+    }
+}
@@ -0,0 +1,531 @@
+use std::io::Write;
+
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+
+use super::{SuperCompiledInstruction, SuperMessageAddressTableLookup, SuperTransactionMessage};
+
+/// Read access to a `SuperTransactionMessage`, implemented by the owned message and by
+/// `SuperTransactionMessageRef` which borrows it straight from the account data.
+pub trait TransactionMessageView {
+    /// The number of signer pubkeys in the account keys.
+    fn num_signers(&self) -> u8;
+    /// The number of writable signer pubkeys in the account keys.
+    fn num_writable_signers(&self) -> u8;
+    /// The number of writable non-signer pubkeys in the account keys.
+    fn num_writable_non_signers(&self) -> u8;
+    /// The number of static account keys.
+    fn num_account_keys(&self) -> usize;
+    /// The static account key at `index`.
+    fn account_key(&self, index: usize) -> Option<Pubkey>;
+    fn num_instructions(&self) -> usize;
+    fn instruction(&self, index: usize) -> Option<SuperCompiledInstructionRef<'_>>;
+    fn num_address_table_lookups(&self) -> usize;
+    fn address_table_lookup(&self, index: usize) -> Option<SuperMessageAddressTableLookupRef<'_>>;
+
+    /// Returns the number of all the account keys (static + dynamic) in the message.
+    fn num_all_account_keys(&self) -> usize {
+        let num_account_keys_from_lookups = (0..self.num_address_table_lookups())
+            .filter_map(|index| self.address_table_lookup(index))
+            .map(|lookup| lookup.writable_indexes.len() + lookup.readonly_indexes.len())
+            .sum::<usize>();
+
+        self.num_account_keys() + num_account_keys_from_lookups
+    }
+
+    /// Returns true if the account at the specified index is a part of static `account_keys` and was requested to be writable.
+    fn is_static_writable_index(&self, key_index: usize) -> bool {
+        let num_account_keys = self.num_account_keys();
+        let num_signers = usize::from(self.num_signers());
+        let num_writable_signers = usize::from(self.num_writable_signers());
+        let num_writable_non_signers = usize::from(self.num_writable_non_signers());
+
+        if key_index >= num_account_keys {
+            // `index` is not a part of static `account_keys`.
+            return false;
+        }
+
+        if key_index < num_writable_signers {
+            // `index` is within the range of writable signer keys.
+            return true;
+        }
+
+        if key_index >= num_signers {
+            // `index` is within the range of non-signer keys.
+            let index_into_non_signers = key_index.saturating_sub(num_signers);
+            // Whether `index` is within the range of writable non-signer keys.
+            return index_into_non_signers < num_writable_non_signers;
+        }
+
+        false
+    }
+
+    /// Returns true if the account at the specified index was requested to be a signer.
+    fn is_signer_index(&self, key_index: usize) -> bool {
+        key_index < usize::from(self.num_signers())
+    }
+}
+
+/// Borrowed `SuperCompiledInstruction`.
+#[derive(Clone, Copy, Debug)]
+pub struct SuperCompiledInstructionRef<'a> {
+    pub program_id_index: u8,
+    pub account_indexes: &'a [u8],
+    pub data: &'a [u8],
+}
+
+/// Borrowed `SuperMessageAddressTableLookup`.
+#[derive(Clone, Copy, Debug)]
+pub struct SuperMessageAddressTableLookupRef<'a> {
+    pub account_key: Pubkey,
+    pub writable_indexes: &'a [u8],
+    pub readonly_indexes: &'a [u8],
+}
+
+/// Zero-copy view of a serialized `SuperTransactionMessage`.
+/// Account keys and instruction data are read from the serialized bytes instead of
+/// being copied on the heap, only the instruction and lookup headers are collected.
+pub struct SuperTransactionMessageRef<'a> {
+    num_signers: u8,
+    num_writable_signers: u8,
+    num_writable_non_signers: u8,
+    /// Serialized account keys, 32 bytes each.
+    account_keys: &'a [u8],
+    instructions: Vec<SuperCompiledInstructionRef<'a>>,
+    address_table_lookups: Vec<SuperMessageAddressTableLookupRef<'a>>,
+}
+
+impl<'a> SuperTransactionMessageRef<'a> {
+    /// Reads a message serialized as a `SuperTransactionMessage` from the start of `buf`
+    /// and advances `buf` past it.
+    pub fn deserialize(buf: &mut &'a [u8]) -> Result<Self> {
+        let num_signers = read_u8(buf)?;
+        let num_writable_signers = read_u8(buf)?;
+        let num_writable_non_signers = read_u8(buf)?;
+
+        let num_account_keys = read_len(buf)?;
+        let account_keys = read_bytes(
+            buf,
+            num_account_keys
+                .checked_mul(32)
+                .ok_or(SuperTxnError::InvalidTransactionMessage)?,
+        )?;
+
+        let num_instructions = read_len(buf)?;
+        let mut instructions = Vec::with_capacity(num_instructions.min(buf.len()));
+        for _ in 0..num_instructions {
+            let program_id_index = read_u8(buf)?;
+            let account_indexes = read_vec(buf)?;
+            let data = read_vec(buf)?;
+            instructions.push(SuperCompiledInstructionRef {
+                program_id_index,
+                account_indexes,
+                data,
+            });
+        }
+
+        let num_address_table_lookups = read_len(buf)?;
+        let mut address_table_lookups =
+            Vec::with_capacity(num_address_table_lookups.min(buf.len()));
+        for _ in 0..num_address_table_lookups {
+            let account_key = Pubkey::try_from(read_bytes(buf, 32)?)
+                .map_err(|_| SuperTxnError::InvalidTransactionMessage)?;
+            let writable_indexes = read_vec(buf)?;
+            let readonly_indexes = read_vec(buf)?;
+            address_table_lookups.push(SuperMessageAddressTableLookupRef {
+                account_key,
+                writable_indexes,
+                readonly_indexes,
+            });
+        }
+
+        Ok(Self {
+            num_signers,
+            num_writable_signers,
+            num_writable_non_signers,
+            account_keys,
+            instructions,
+            address_table_lookups,
+        })
+    }
+
+    /// Reads a `TransactionMessage` serialized in `transaction_message`, validated like
+    /// `SuperTransactionMessage::try_from`, as a view of the message it converts to.
+    pub fn from_transaction_message(mut transaction_message: &'a [u8]) -> Result<Self> {
+        super_transaction_message_size(transaction_message)?;
+
+        let buf = &mut transaction_message;
+        let num_signers = read_u8(buf)?;
+        let num_writable_signers = read_u8(buf)?;
+        let num_writable_non_signers = read_u8(buf)?;
+
+        let num_account_keys = usize::from(read_u8(buf)?);
+        let account_keys = read_bytes(buf, num_account_keys * 32)?;
+
+        let num_instructions = usize::from(read_u8(buf)?);
+        let mut instructions = Vec::with_capacity(num_instructions);
+        for _ in 0..num_instructions {
+            let program_id_index = read_u8(buf)?;
+            let num_account_indexes = usize::from(read_u8(buf)?);
+            let account_indexes = read_bytes(buf, num_account_indexes)?;
+            let data_len = usize::from(u16::from_le_bytes(read_bytes(buf, 2)?.try_into().unwrap()));
+            let data = read_bytes(buf, data_len)?;
+            instructions.push(SuperCompiledInstructionRef {
+                program_id_index,
+                account_indexes,
+                data,
+            });
+        }
+
+        let num_address_table_lookups = usize::from(read_u8(buf)?);
+        let mut address_table_lookups = Vec::with_capacity(num_address_table_lookups);
+        for _ in 0..num_address_table_lookups {
+            let account_key = Pubkey::try_from(read_bytes(buf, 32)?).unwrap();
+            let num_writable_indexes = usize::from(read_u8(buf)?);
+            let writable_indexes = read_bytes(buf, num_writable_indexes)?;
+            let num_readonly_indexes = usize::from(read_u8(buf)?);
+            let readonly_indexes = read_bytes(buf, num_readonly_indexes)?;
+            address_table_lookups.push(SuperMessageAddressTableLookupRef {
+                account_key,
+                writable_indexes,
+                readonly_indexes,
+            });
+        }
+
+        Ok(Self {
+            num_signers,
+            num_writable_signers,
+            num_writable_non_signers,
+            account_keys,
+            instructions,
+            address_table_lookups,
+        })
+    }
+
+    /// Serializes the message as a `SuperTransactionMessage`, e.g. straight into account data.
+    pub fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&[
+            self.num_signers,
+            self.num_writable_signers,
+            self.num_writable_non_signers,
+        ])?;
+        write_vec(writer, self.num_account_keys(), self.account_keys)?;
+
+        write_len(writer, self.instructions.len())?;
+        for instruction in &self.instructions {
+            writer.write_all(&[instruction.program_id_index])?;
+            write_vec(
+                writer,
+                instruction.account_indexes.len(),
+                instruction.account_indexes,
+            )?;
+            write_vec(writer, instruction.data.len(), instruction.data)?;
+        }
+
+        write_len(writer, self.address_table_lookups.len())?;
+        for lookup in &self.address_table_lookups {
+            writer.write_all(lookup.account_key.as_ref())?;
+            write_vec(
+                writer,
+                lookup.writable_indexes.len(),
+                lookup.writable_indexes,
+            )?;
+            write_vec(
+                writer,
+                lookup.readonly_indexes.len(),
+                lookup.readonly_indexes,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates a `TransactionMessage` serialized in `transaction_message` the same way as
+/// `SuperTransactionMessage::try_from` and returns the serialized length of the resulting
+/// `SuperTransactionMessage` and its number of instructions, without copying it on the heap.
+pub fn super_transaction_message_size(mut transaction_message: &[u8]) -> Result<(usize, usize)> {
+    let buf = &mut transaction_message;
+    let num_signers = read_u8(buf)?;
+    let num_writable_signers = read_u8(buf)?;
+    let num_writable_non_signers = read_u8(buf)?;
+
+    let num_account_keys = usize::from(read_u8(buf)?);
+    read_bytes(buf, num_account_keys * 32)?;
+
+    // Instruction indices are checked against the lookups that follow the instructions.
+    let mut max_index = None;
+    let num_instructions = usize::from(read_u8(buf)?);
+    let mut instructions_size = 0;
+    for _ in 0..num_instructions {
+        let program_id_index = read_u8(buf)?;
+        let num_account_indexes = usize::from(read_u8(buf)?);
+        let account_indexes = read_bytes(buf, num_account_indexes)?;
+        let data_len = usize::from(u16::from_le_bytes(read_bytes(buf, 2)?.try_into().unwrap()));
+        read_bytes(buf, data_len)?;
+
+        max_index = account_indexes
+            .iter()
+            .copied()
+            .chain([program_id_index])
+            .max()
+            .max(max_index);
+        instructions_size += 1 + 4 + num_account_indexes + 4 + data_len;
+    }
+
+    let num_address_table_lookups = usize::from(read_u8(buf)?);
+    let mut num_all_account_keys = num_account_keys;
+    let mut address_table_lookups_size = 0;
+    for _ in 0..num_address_table_lookups {
+        read_bytes(buf, 32)?;
+        let num_writable_indexes = usize::from(read_u8(buf)?);
+        read_bytes(buf, num_writable_indexes)?;
+        let num_readonly_indexes = usize::from(read_u8(buf)?);
+        read_bytes(buf, num_readonly_indexes)?;
+
+        num_all_account_keys += num_writable_indexes + num_readonly_indexes;
+        address_table_lookups_size += 32 + 4 + num_writable_indexes + 4 + num_readonly_indexes;
+    }
+
+    require!(
+        usize::from(num_signers) <= num_account_keys,
+        SuperTxnError::InvalidTransactionMessage
+    );
+    require!(
+        num_writable_signers <= num_signers,
+        SuperTxnError::InvalidTransactionMessage
+    );
+    require!(
+        usize::from(num_writable_non_signers) <= num_account_keys - usize::from(num_signers),
+        SuperTxnError::InvalidTransactionMessage
+    );
+    require!(
+        max_index.map_or(true, |index| usize::from(index) < num_all_account_keys),
+        SuperTxnError::InvalidTransactionMessage
+    );
+
+    let size = 3 + // num_signers, num_writable_signers, num_writable_non_signers
+        4 + num_account_keys * 32 + // account_keys
+        4 + instructions_size + // instructions
+        4 + address_table_lookups_size; // address_table_lookups
+
+    Ok((size, num_instructions))
+}
+
+fn read_bytes<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    require!(len <= buf.len(), SuperTxnError::InvalidTransactionMessage);
+    let (bytes, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(bytes)
+}
+
+fn read_u8(buf: &mut &[u8]) -> Result<u8> {
+    Ok(read_bytes(buf, 1)?[0])
+}
+
+/// Reads the `u32` length prefix of a borsh vector.
+fn read_len(buf: &mut &[u8]) -> Result<usize> {
+    let len = read_bytes(buf, 4)?;
+    Ok(u32::from_le_bytes(len.try_into().unwrap()) as usize)
+}
+
+/// Reads a borsh `Vec<u8>`.
+fn read_vec<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let len = read_len(buf)?;
+    read_bytes(buf, len)
+}
+
+/// Writes the `u32` length prefix of a borsh vector.
+fn write_len<W: Write>(writer: &mut W, len: usize) -> Result<()> {
+    let len = u32::try_from(len).map_err(|_| SuperTxnError::InvalidTransactionMessage)?;
+    writer.write_all(&len.to_le_bytes())?;
+    Ok(())
+}
+
+/// Writes a borsh vector of `len` elements serialized in `bytes`.
+fn write_vec<W: Write>(writer: &mut W, len: usize, bytes: &[u8]) -> Result<()> {
+    write_len(writer, len)?;
+    writer.write_all(bytes)?;
+    Ok(())
+}
+
+impl TransactionMessageView for SuperTransactionMessageRef<'_> {
+    fn num_signers(&self) -> u8 {
+        self.num_signers
+    }
+
+    fn num_writable_signers(&self) -> u8 {
+        self.num_writable_signers
+    }
+
+    fn num_writable_non_signers(&self) -> u8 {
+        self.num_writable_non_signers
+    }
+
+    fn num_account_keys(&self) -> usize {
+        self.account_keys.len() / 32
+    }
+
+    fn account_key(&self, index: usize) -> Option<Pubkey> {
+        let start = index.checked_mul(32)?;
+        let key = self.account_keys.get(start..start.checked_add(32)?)?;
+        Pubkey::try_from(key).ok()
+    }
+
+    fn num_instructions(&self) -> usize {
+        self.instructions.len()
+    }
+
+    fn instruction(&self, index: usize) -> Option<SuperCompiledInstructionRef<'_>> {
+        self.instructions.get(index).copied()
+    }
+
+    fn num_address_table_lookups(&self) -> usize {
+        self.address_table_lookups.len()
+    }
+
+    fn address_table_lookup(&self, index: usize) -> Option<SuperMessageAddressTableLookupRef<'_>> {
+        self.address_table_lookups.get(index).copied()
+    }
+}
+
+impl TransactionMessageView for SuperTransactionMessage {
+    fn num_signers(&self) -> u8 {
+        self.num_signers
+    }
+
+    fn num_writable_signers(&self) -> u8 {
+        self.num_writable_signers
+    }
+
+    fn num_writable_non_signers(&self) -> u8 {
+        self.num_writable_non_signers
+    }
+
+    fn num_account_keys(&self) -> usize {
+        self.account_keys.len()
+    }
+
+    fn account_key(&self, index: usize) -> Option<Pubkey> {
+        self.account_keys.get(index).copied()
+    }
+
+    fn num_instructions(&self) -> usize {
+        self.instructions.len()
+    }
+
+    fn instruction(&self, index: usize) -> Option<SuperCompiledInstructionRef<'_>> {
+        self.instructions
+            .get(index)
+            .map(SuperCompiledInstruction::as_ref)
+    }
+
+    fn num_address_table_lookups(&self) -> usize {
+        self.address_table_lookups.len()
+    }
+
+    fn address_table_lookup(&self, index: usize) -> Option<SuperMessageAddressTableLookupRef<'_>> {
+        self.address_table_lookups
+            .get(index)
+            .map(SuperMessageAddressTableLookup::as_ref)
+    }
+}
+
+impl SuperCompiledInstruction {
+    pub fn as_ref(&self) -> SuperCompiledInstructionRef<'_> {
+        SuperCompiledInstructionRef {
+            program_id_index: self.program_id_index,
+            account_indexes: &self.account_indexes,
+            data: &self.data,
+        }
+    }
+}
+
+impl SuperMessageAddressTableLookup {
+    pub fn as_ref(&self) -> SuperMessageAddressTableLookupRef<'_> {
+        SuperMessageAddressTableLookupRef {
+            account_key: self.account_key,
+            writable_indexes: &self.writable_indexes,
+            readonly_indexes: &self.readonly_indexes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ref_matches_owned_message() {
+        let message = SuperTransactionMessage {
+            num_signers: 2,
+            num_writable_signers: 1,
+            num_writable_non_signers: 1,
+            account_keys: vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ],
+            instructions: vec![
+                SuperCompiledInstruction {
+                    program_id_index: 3,
+                    account_indexes: vec![0, 1, 2, 4],
+                    data: vec![1, 2, 3],
+                },
+                SuperCompiledInstruction {
+                    program_id_index: 3,
+                    account_indexes: vec![],
+                    data: vec![],
+                },
+            ],
+            address_table_lookups: vec![SuperMessageAddressTableLookup {
+                account_key: Pubkey::new_unique(),
+                writable_indexes: vec![7],
+                readonly_indexes: vec![1, 2],
+            }],
+        };
+        let mut bytes = message.try_to_vec().unwrap();
+        bytes.extend_from_slice(&[9, 9]);
+
+        let mut buf = bytes.as_slice();
+        let message_ref = SuperTransactionMessageRef::deserialize(&mut buf).unwrap();
+        assert_eq!(buf, &[9, 9]);
+
+        assert_eq!(message_ref.num_signers(), message.num_signers());
+        assert_eq!(message_ref.num_writable_signers(), 1);
+        assert_eq!(message_ref.num_writable_non_signers(), 1);
+        assert_eq!(message_ref.num_all_account_keys(), 7);
+        for index in 0..=message.account_keys.len() {
+            assert_eq!(message_ref.account_key(index), message.account_key(index));
+            assert_eq!(
+                message_ref.is_static_writable_index(index),
+                message.is_static_writable_index(index)
+            );
+        }
+        assert_eq!(message_ref.num_instructions(), 2);
+        for index in 0..2 {
+            let (instruction_ref, instruction) = (
+                message_ref.instruction(index).unwrap(),
+                message.instruction(index).unwrap(),
+            );
+            assert_eq!(
+                instruction_ref.program_id_index,
+                instruction.program_id_index
+            );
+            assert_eq!(instruction_ref.account_indexes, instruction.account_indexes);
+            assert_eq!(instruction_ref.data, instruction.data);
+        }
+        let lookup = message_ref.address_table_lookup(0).unwrap();
+        assert_eq!(
+            lookup.account_key,
+            message.address_table_lookups[0].account_key
+        );
+        assert_eq!(lookup.writable_indexes, &[7]);
+        assert_eq!(lookup.readonly_indexes, &[1, 2]);
+
+        // Truncated messages are rejected.
+        let mut truncated = &bytes[..bytes.len() - 10];
+        assert!(SuperTransactionMessageRef::deserialize(&mut truncated).is_err());
+    }
+}
@@ -35,3 +35,39 @@ pub fn derive_ephemeral_signers(
         })
         .unzip()
 }
+
+/// Return the bumps of the first `ephemeral_signers` ephemeral signer PDAs
+/// of the transaction at `transaction_key`.
+pub fn derive_ephemeral_signer_bumps(transaction_key: &Pubkey, ephemeral_signers: u8) -> Vec<u8> {
+    (0..ephemeral_signers)
+        .map(|ephemeral_signer_index| {
+            let ephemeral_signer_seeds = &[
+                SEED_PREFIX,
+                transaction_key.as_ref(),
+                SEED_EPHEMERAL_SIGNER,
+                &ephemeral_signer_index.to_le_bytes(),
+            ];
+
+            let (_, bump) = Pubkey::find_program_address(ephemeral_signer_seeds, &crate::id());
+            bump
+        })
+        .collect()
+}
+
+/// Return the key and the seeds of the authority PDA of `creator`.
+/// The program signs for it on every transaction of `creator`, so messages can
+/// use it as a signer regardless of who executes them.
+pub fn derive_creator_authority(creator: &Pubkey) -> (Pubkey, Vec<Vec<u8>>) {
+    let (key, bump) = Pubkey::find_program_address(
+        &[SEED_PREFIX, SEED_CREATOR_AUTHORITY, creator.as_ref()],
+        &crate::id(),
+    );
+    let seeds = vec![
+        SEED_PREFIX.to_vec(),
+        SEED_CREATOR_AUTHORITY.to_vec(),
+        creator.to_bytes().to_vec(),
+        vec![bump],
+    ];
+
+    (key, seeds)
+}
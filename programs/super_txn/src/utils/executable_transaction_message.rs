@@ -1,14 +1,48 @@
+use std::ops::Range;
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::address_lookup_table::{self, state::AddressLookupTable};
+use anchor_lang::solana_program::compute_units::sol_remaining_compute_units;
 use anchor_lang::solana_program::instruction::Instruction;
-use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program::{get_return_data, invoke_signed};
 
+use crate::allocator;
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 
+/// How the instructions of a message are executed.
+#[derive(Clone, Copy, Default)]
+pub struct ExecutionMode {
+    /// Skip instructions that reference missing accounts or a non-executable program
+    /// instead of failing, since CPI errors can't be caught.
+    pub best_effort: bool,
+    /// Capture the return data of every executed instruction.
+    pub capture_return_data: bool,
+    /// Stop before an instruction once fewer compute units than this are left.
+    pub min_remaining_compute_units: Option<u64>,
+}
+
+/// Outcome of `ExecutableTransactionMessage::execute_message`.
+#[derive(Default)]
+pub struct ExecutionOutcome {
+    /// Index of the next instruction to execute, before the end of the range
+    /// if execution stopped on `min_remaining_compute_units`.
+    pub next_instruction_index: usize,
+    /// Indexes of the instructions skipped in best-effort mode.
+    pub skipped_instructions: Vec<usize>,
+    /// Return data of the executed instructions, if captured.
+    pub return_data: Vec<InstructionReturnData>,
+    /// Compute units consumed by each executed instruction.
+    pub compute_units: Vec<InstructionComputeUnits>,
+}
+
 /// Sanitized and validated combination of a `MsTransactionMessage` and `AccountInfo`s it references.
-pub struct ExecutableTransactionMessage<'a, 'info> {
+/// `M` is either the owned `SuperTransactionMessage` or a `SuperTransactionMessageRef`
+/// borrowed from the account data.
+pub struct ExecutableTransactionMessage<'a, 'info, M: TransactionMessageView> {
     /// Message which loaded a collection of lookup table addresses.
-    message: SuperTransactionMessage,
+    message: &'a M,
     /// Resolved `account_keys` of the message.
     static_accounts: Vec<&'a AccountInfo<'info>>,
     /// Concatenated vector of resolved `writable_indexes` from all address lookups.
@@ -17,46 +51,45 @@ pub struct ExecutableTransactionMessage<'a, 'info> {
     loaded_readonly_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
-impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
+impl<'a, 'info, M: TransactionMessageView> ExecutableTransactionMessage<'a, 'info, M> {
     /// # Arguments
-    /// `message` - a `TransactionMessage`.
+    /// `message` - a `SuperTransactionMessage` or a view of it.
     /// `message_account_infos` - AccountInfo's that are expected to be mentioned in the message.
     /// `address_lookup_table_account_infos` - AccountInfo's that are expected to correspond to the lookup tables mentioned in `message.address_table_lookups`.
-    /// `creator_key` - The creator that is expected to sign the message.
+    /// `transaction_key` - The executing transaction, it can't be referenced as writable.
+    /// `ephemeral_signer_pdas` - PDAs the program signs for, the ephemeral signers and the creator authority.
+    /// `execution_policy` - Policy every program called by the message must be allowed by.
     pub fn new_validated(
-        message: SuperTransactionMessage,
+        message: &'a M,
         message_account_infos: &'a [AccountInfo<'info>],
-        creator_key: &'a Pubkey,
+        address_lookup_table_account_infos: &'a [AccountInfo<'info>],
+        transaction_key: &Pubkey,
         ephemeral_signer_pdas: &'a [Pubkey],
+        execution_policy: &ExecutionPolicy,
     ) -> Result<Self> {
         // CHECK: `address_lookup_table_account_infos` must be valid `AddressLookupTable`s
         //         and be the ones mentioned in `message.address_table_lookups`.
-        // require_eq!(
-        //     address_lookup_table_account_infos.len(),
-        //     message.address_table_lookups.len(),
-        //     SuperTxnError::InvalidNumberOfAccounts
-        // );
-        // let lookup_tables: HashMap<&Pubkey, &AccountInfo> = address_lookup_table_account_infos
-        //     .iter()
-        //     .enumerate()
-        //     .map(|(index, maybe_lookup_table)| {
-        //         // The lookup table account must be owned by SolanaAddressLookupTableProgram.
-        //         require!(
-        //             maybe_lookup_table.owner == &address_lookup_table::program::ID,
-        //             SuperTxnError::InvalidAccount
-        //         );
-        //         // The lookup table must be mentioned in `message.address_table_lookups` at the same index.
-        //         require!(
-        //             message
-        //                 .address_table_lookups
-        //                 .get(index)
-        //                 .map(|lookup| &lookup.account_key)
-        //                 == Some(maybe_lookup_table.key),
-        //             SuperTxnError::InvalidAccount
-        //         );
-        //         Ok((maybe_lookup_table.key, maybe_lookup_table))
-        //     })
-        //     .collect::<Result<HashMap<&Pubkey, &AccountInfo>>>()?;
+        require_eq!(
+            address_lookup_table_account_infos.len(),
+            message.num_address_table_lookups(),
+            SuperTxnError::InvalidNumberOfAccounts
+        );
+        for (index, maybe_lookup_table) in address_lookup_table_account_infos.iter().enumerate() {
+            let lookup = message
+                .address_table_lookup(index)
+                .ok_or(SuperTxnError::InvalidTransactionMessage)?;
+            // The lookup table account must be owned by SolanaAddressLookupTableProgram.
+            require!(
+                maybe_lookup_table.owner == &address_lookup_table::program::ID,
+                SuperTxnError::InvalidAccount
+            );
+            // The lookup table must be mentioned in `message.address_table_lookups` at the same index.
+            require_keys_eq!(
+                *maybe_lookup_table.key,
+                lookup.account_key,
+                SuperTxnError::InvalidAccount
+            );
+        }
 
         // CHECK: `account_infos` should exactly match the number of accounts mentioned in the message.
         require_eq!(
@@ -68,25 +101,30 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
         let mut static_accounts = Vec::new();
 
         // CHECK: `message.account_keys` should come first in `account_infos` and have modifiers expected by the message.
-        for (i, account_key) in message.account_keys.iter().enumerate() {
-            let account_info = &message_account_infos[i];
+        for (i, account_info) in message_account_infos
+            .iter()
+            .take(message.num_account_keys())
+            .enumerate()
+        {
+            let account_key = message
+                .account_key(i)
+                .ok_or(SuperTxnError::InvalidTransactionMessage)?;
             require_keys_eq!(
                 *account_info.key,
-                *account_key,
+                account_key,
                 SuperTxnError::InvalidAccount
             );
             // If the account is marked as signer in the message, it must be a signer in the account infos too.
-            // Unless it's a creator(signer) or an ephemeral signer PDA, as they cannot be passed as signers to `remaining_accounts`,
-            // because they are PDA's and can't sign the transaction.
-            if message.is_signer_index(i)
-                && account_info.key != creator_key
-                && !ephemeral_signer_pdas.contains(account_info.key)
-            {
+            // Unless it's an ephemeral signer or the creator authority PDA, as they cannot be passed as signers
+            // to `remaining_accounts`, because they are PDA's and can't sign the transaction.
+            // The creator is no exception, it only signs when it is the executor.
+            if message.is_signer_index(i) && !ephemeral_signer_pdas.contains(account_info.key) {
                 require!(account_info.is_signer, SuperTxnError::InvalidAccount);
             }
             // If the account is marked as writable in the message, it must be writable in the account infos too.
             if message.is_static_writable_index(i) {
                 require!(account_info.is_writable, SuperTxnError::InvalidAccount);
+                check_not_protected(account_info, transaction_key)?;
             }
             static_accounts.push(account_info);
         }
@@ -97,19 +135,20 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
         // CHECK: `message_account_infos` loaded with lookup tables should come after `message.account_keys`,
         //        in the same order and with the same modifiers as listed in lookups.
         // Track where we are in the message account indexes. Start after `message.account_keys`.
-        let mut message_indexes_cursor = message.account_keys.len();
-        for lookup in message.address_table_lookups.iter() {
+        let mut message_indexes_cursor = message.num_account_keys();
+        for (index, lookup_table_account_info) in
+            address_lookup_table_account_infos.iter().enumerate()
+        {
+            let lookup = message
+                .address_table_lookup(index)
+                .ok_or(SuperTxnError::InvalidTransactionMessage)?;
             // This is cheap deserialization, it doesn't allocate/clone space for addresses.
-            // let lookup_table_data = &lookup_tables
-            //     .get(&lookup.account_key)
-            //     .unwrap()
-            //     .data
-            //     .borrow()[..];
-            // let lookup_table = AddressLookupTable::deserialize(lookup_table_data)
-            //     .map_err(|_| SuperTxnError::InvalidAccount)?;
+            let lookup_table_data = &lookup_table_account_info.data.borrow()[..];
+            let lookup_table = AddressLookupTable::deserialize(lookup_table_data)
+                .map_err(|_| SuperTxnError::InvalidAccount)?;
 
             // Accounts listed as writable in lookup, should be loaded as writable.
-            for (i, _index_in_lookup_table) in lookup.writable_indexes.iter().enumerate() {
+            for (i, index_in_lookup_table) in lookup.writable_indexes.iter().enumerate() {
                 // Check the modifiers.
                 let index = message_indexes_cursor + i;
                 let loaded_account_info = &message_account_infos
@@ -121,58 +160,83 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
                     SuperTxnError::InvalidAccount
                 );
                 // Check that the pubkey matches the one from the actual lookup table.
-                // let pubkey_from_lookup_table = lookup_table
-                //     .addresses
-                //     .get(usize::from(*index_in_lookup_table))
-                //     .ok_or(SuperTxnError::InvalidAccount)?;
-                // require_keys_eq!(
-                //     *loaded_account_info.key,
-                //     *pubkey_from_lookup_table,
-                //     SuperTxnError::InvalidAccount
-                // );
+                let pubkey_from_lookup_table = lookup_table
+                    .addresses
+                    .get(usize::from(*index_in_lookup_table))
+                    .ok_or(SuperTxnError::InvalidAccount)?;
+                require_keys_eq!(
+                    *loaded_account_info.key,
+                    *pubkey_from_lookup_table,
+                    SuperTxnError::InvalidAccount
+                );
+                check_not_protected(loaded_account_info, transaction_key)?;
 
                 writable_accounts.push(*loaded_account_info);
             }
             message_indexes_cursor += lookup.writable_indexes.len();
 
             // Accounts listed as readonly in lookup.
-            for (i, _index_in_lookup_table) in lookup.readonly_indexes.iter().enumerate() {
+            for (i, index_in_lookup_table) in lookup.readonly_indexes.iter().enumerate() {
                 // Check the modifiers.
                 let index = message_indexes_cursor + i;
                 let loaded_account_info = &message_account_infos
                     .get(index)
                     .ok_or(SuperTxnError::InvalidNumberOfAccounts)?;
                 // Check that the pubkey matches the one from the actual lookup table.
-                // let pubkey_from_lookup_table = lookup_table
-                //     .addresses
-                //     .get(usize::from(*index_in_lookup_table))
-                //     .ok_or(SuperTxnError::InvalidAccount)?;
-                // require_keys_eq!(
-                //     *loaded_account_info.key,
-                //     *pubkey_from_lookup_table,
-                //     SuperTxnError::InvalidAccount
-                // );
+                let pubkey_from_lookup_table = lookup_table
+                    .addresses
+                    .get(usize::from(*index_in_lookup_table))
+                    .ok_or(SuperTxnError::InvalidAccount)?;
+                require_keys_eq!(
+                    *loaded_account_info.key,
+                    *pubkey_from_lookup_table,
+                    SuperTxnError::InvalidAccount
+                );
 
                 readonly_accounts.push(*loaded_account_info);
             }
             message_indexes_cursor += lookup.readonly_indexes.len();
         }
 
-        Ok(Self {
+        let executable_message = Self {
             message,
             static_accounts,
             loaded_writable_accounts: writable_accounts,
             loaded_readonly_accounts: readonly_accounts,
-        })
+        };
+
+        // CHECK: every program called by the message must be allowed by the execution policy.
+        for index in 0..message.num_instructions() {
+            let instruction = message
+                .instruction(index)
+                .ok_or(SuperTxnError::InvalidTransactionMessage)?;
+            let program_id = executable_message
+                .get_account_by_index(usize::from(instruction.program_id_index))?
+                .key;
+            if !execution_policy.allows(program_id) {
+                msg!(
+                    "Instruction {} calls program {} which is not allowed",
+                    index,
+                    program_id
+                );
+                return err!(SuperTxnError::ProgramNotAllowed);
+            }
+        }
+
+        Ok(executable_message)
     }
 
-    /// Executes all instructions in the message via CPI calls.
+    /// Executes the instructions of the message in `instruction_range` via CPI calls.
     /// # Arguments
-    /// * `ephemeral_signer_seeds` - Seeds for the ephemeral signer PDAs.
+    /// * `instruction_range` - Range of `message.instructions` to execute.
+    /// * `ephemeral_signer_seeds` - Seeds for the ephemeral signer PDAs and the creator authority.
+    /// * `mode` - How failing instructions and return data are handled.
     pub fn execute_message(
-        self,
+        &self,
+        instruction_range: Range<usize>,
         ephemeral_signer_seeds: &[Vec<Vec<u8>>],
-    ) -> Result<()> {
+        mode: ExecutionMode,
+    ) -> Result<ExecutionOutcome> {
         // First round of type conversion; from Vec<Vec<Vec<u8>>> to Vec<Vec<&[u8]>>.
         let ephemeral_signer_seeds = &ephemeral_signer_seeds
             .iter()
@@ -184,21 +248,109 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
             .map(Vec::as_slice)
             .collect::<Vec<&[&[u8]]>>();
 
-        // NOTE: `self.to_instructions_and_accounts()` calls `take()` on
-        // `self.message.instructions`, therefore after this point no more
-        // references or usages of `self.message` should be made to avoid
-        // faulty behavior.
-        for (ix, account_infos) in self.to_instructions_and_accounts().iter() {
-            invoke_signed(&ix, &account_infos, &signer_seeds)?;
+        let mut outcome = ExecutionOutcome {
+            next_instruction_index: instruction_range.end,
+            ..Default::default()
+        };
+        let start = instruction_range.start;
+        if instruction_range.end > self.message.num_instructions() {
+            msg!(
+                "Instruction index {} is out of range",
+                instruction_range.end - 1
+            );
+            return err!(SuperTxnError::InvalidTransactionMessage);
         }
-        Ok(())
+
+        // Scratch buffers every instruction is built into just before it is invoked.
+        // They are sized for the largest instruction upfront so they never grow within
+        // the heap scopes below.
+        let (max_accounts, max_data_len) = instruction_range
+            .clone()
+            .filter_map(|index| self.message.instruction(index))
+            .fold((0, 0), |(max_accounts, max_data_len), ix| {
+                (
+                    max_accounts.max(ix.account_indexes.len()),
+                    max_data_len.max(ix.data.len()),
+                )
+            });
+        let mut ix = Instruction {
+            program_id: Pubkey::default(),
+            accounts: Vec::with_capacity(max_accounts),
+            data: Vec::with_capacity(max_data_len),
+        };
+        let mut account_infos = Vec::with_capacity(max_accounts + 1);
+
+        for index in instruction_range {
+            if let Some(min_remaining_compute_units) = mode.min_remaining_compute_units {
+                if sol_remaining_compute_units() < min_remaining_compute_units {
+                    msg!(
+                        "Stopping before instruction {}, less than {} compute units left",
+                        index,
+                        min_remaining_compute_units
+                    );
+                    outcome.next_instruction_index = index;
+                    break;
+                }
+            }
+
+            // The instruction is invoked in a heap scope, so peak heap usage depends on
+            // the largest instruction rather than on all of them.
+            // Returns the consumed compute units, `None` if the instruction is skipped.
+            let compute_units = allocator::scoped(|| -> Result<Option<u64>> {
+                self.build_instruction(index, &mut ix, &mut account_infos)?;
+                if mode.best_effort && !is_executable(&ix, &account_infos) {
+                    msg!("Skipping instruction {}", index);
+                    return Ok(None);
+                }
+                // A failing CPI aborts the whole transaction and can't be caught,
+                // the last logged instruction is the one that failed.
+                emit!(InstructionInvoked {
+                    index: u8::try_from(index).unwrap_or(u8::MAX),
+                    program_id: ix.program_id,
+                });
+                let compute_units_before = sol_remaining_compute_units();
+                invoke_signed(&ix, &account_infos, &signer_seeds)?;
+                Ok(Some(
+                    compute_units_before.saturating_sub(sol_remaining_compute_units()),
+                ))
+            })?;
+            let Some(compute_units) = compute_units else {
+                outcome.skipped_instructions.push(index);
+                continue;
+            };
+            outcome.compute_units.push(InstructionComputeUnits {
+                index: u8::try_from(index).unwrap_or(u8::MAX),
+                units: u32::try_from(compute_units).unwrap_or(u32::MAX),
+            });
+
+            // The return data is reset by every CPI, so it belongs to this instruction.
+            if mode.capture_return_data {
+                if let Some((program_id, data)) = get_return_data() {
+                    outcome.return_data.push(InstructionReturnData {
+                        index: u8::try_from(index).unwrap_or(u8::MAX),
+                        program_id,
+                        data,
+                    });
+                }
+            }
+        }
+
+        if !outcome.compute_units.is_empty() {
+            let compute_units: Vec<u32> = outcome.compute_units.iter().map(|cu| cu.units).collect();
+            msg!(
+                "Compute units from instruction {}: {:?}",
+                start,
+                compute_units
+            );
+        }
+        Ok(outcome)
     }
 
     /// Account indices are resolved in the following order:
     /// 1. Static accounts.
     /// 2. All loaded writable accounts.
     /// 3. All loaded readonly accounts.
-    fn get_account_by_index(&self, index: usize) -> Result<&'a AccountInfo<'info>> {
+    pub fn get_account_by_index(&self, index: usize) -> Result<&'a AccountInfo<'info>> {
         if index < self.static_accounts.len() {
             return Ok(self.static_accounts[index]);
         }
@@ -233,54 +385,83 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
         index < self.loaded_writable_accounts.len()
     }
 
-    pub fn to_instructions_and_accounts(mut self) -> Vec<(Instruction, Vec<AccountInfo<'info>>)> {
-        let mut executable_instructions = vec![];
-
-        for super_compiled_instruction in core::mem::take(&mut self.message.instructions) {
-            let ix_accounts: Vec<(AccountInfo<'info>, AccountMeta)> = super_compiled_instruction
-                .account_indexes
-                .iter()
-                .map(|account_index| {
-                    let account_index = usize::from(*account_index);
-                    let account_info = self.get_account_by_index(account_index).unwrap();
-
-                    // `is_signer` cannot just be taken from the account info, because for `authority`
-                    // it's always false in the passed account infos, but might be true in the actual instructions.
-                    let is_signer = self.message.is_signer_index(account_index);
-
-                    let account_meta = if self.is_writable_index(account_index) {
-                        AccountMeta::new(*account_info.key, is_signer)
-                    } else {
-                        AccountMeta::new_readonly(*account_info.key, is_signer)
-                    };
-
-                    (account_info.clone(), account_meta)
-                })
-                .collect();
-
-            let ix_program_account_info = self
-                .get_account_by_index(usize::from(super_compiled_instruction.program_id_index))
-                .unwrap();
-
-            let ix = Instruction {
-                program_id: *ix_program_account_info.key,
-                accounts: ix_accounts
-                    .iter()
-                    .map(|(_, account_meta)| account_meta.clone())
-                    .collect(),
-                data: super_compiled_instruction.data,
-            };
+    /// Builds the instruction at `index` into the `ix` and `account_infos` scratch buffers,
+    /// reused across instructions. `account_infos` ends with the program account.
+    fn build_instruction(
+        &self,
+        index: usize,
+        ix: &mut Instruction,
+        account_infos: &mut Vec<AccountInfo<'info>>,
+    ) -> Result<()> {
+        let super_compiled_instruction = self.message.instruction(index).ok_or_else(|| {
+            msg!("Instruction index {} is out of range", index);
+            SuperTxnError::InvalidTransactionMessage
+        })?;
+        let get_account = |account_index: usize| {
+            self.get_account_by_index(account_index).map_err(|err| {
+                msg!(
+                    "Account index {} of instruction {} is out of range",
+                    account_index,
+                    index
+                );
+                err
+            })
+        };
+
+        ix.accounts.clear();
+        account_infos.clear();
+        for account_index in super_compiled_instruction.account_indexes {
+            let account_index = usize::from(*account_index);
+            let account_info = get_account(account_index)?;
+
+            // `is_signer` cannot just be taken from the account info, because for `authority`
+            // it's always false in the passed account infos, but might be true in the actual instructions.
+            let is_signer = self.message.is_signer_index(account_index);
+
+            ix.accounts.push(if self.is_writable_index(account_index) {
+                AccountMeta::new(*account_info.key, is_signer)
+            } else {
+                AccountMeta::new_readonly(*account_info.key, is_signer)
+            });
+            account_infos.push(account_info.clone());
+        }
 
-            let mut account_infos: Vec<AccountInfo> = ix_accounts
-                .into_iter()
-                .map(|(account_info, _)| account_info)
-                .collect();
-            // Add Program ID
-            account_infos.push(ix_program_account_info.clone());
+        let ix_program_account_info =
+            get_account(usize::from(super_compiled_instruction.program_id_index))?;
+        ix.program_id = *ix_program_account_info.key;
+        ix.data.clear();
+        ix.data.extend_from_slice(super_compiled_instruction.data);
 
-            executable_instructions.push((ix, account_infos));
-        }
+        // Add Program ID
+        account_infos.push(ix_program_account_info.clone());
 
-        executable_instructions
+        Ok(())
     }
 }
+
+/// Accounts the message requests as writable can't be the executing transaction
+/// or any other account owned by super_txn, e.g. a `TransactionBuffer`.
+fn check_not_protected(account_info: &AccountInfo, transaction_key: &Pubkey) -> Result<()> {
+    if account_info.key == transaction_key || account_info.owner == &crate::ID {
+        msg!("Account {} is protected", account_info.key);
+        return err!(SuperTxnError::ProtectedAccount);
+    }
+    Ok(())
+}
+
+/// Whether the program of `ix` is executable and none of its readonly accounts is missing.
+/// Writable accounts may be created by the instruction itself.
+/// `account_infos` ends with the program account, as built by `build_instruction`.
+fn is_executable(ix: &Instruction, account_infos: &[AccountInfo]) -> bool {
+    let Some((program_account_info, account_infos)) = account_infos.split_last() else {
+        return false;
+    };
+    program_account_info.executable
+        && ix
+            .accounts
+            .iter()
+            .zip(account_infos)
+            .all(|(account_meta, account_info)| {
+                account_meta.is_writable || account_info.lamports() > 0
+            })
+}
@@ -13,4 +13,40 @@ pub fn close<'info>(info: AccountInfo<'info>, sol_destination: AccountInfo<'info
 
     info.assign(&system_program::ID);
     info.realloc(0, false).map_err(Into::into)
-}
\ No newline at end of file
+}
+
+/// Minimum lamports an account of `len` bytes must hold to stay rent exempt.
+pub fn rent_exempt_lamports(len: usize) -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(len).max(1))
+}
+
+/// Reallocates `info` to `new_len`, first topping it up from `payer` so it stays rent exempt.
+/// `reserved_lamports` held by the account on top of its rent, e.g. an escrowed bounty, are not counted.
+///
+/// An account can only grow by `MAX_PERMITTED_DATA_INCREASE` bytes per instruction.
+pub fn realloc<'info>(
+    info: &AccountInfo<'info>,
+    new_len: usize,
+    reserved_lamports: u64,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    // Calculate the rent exemption for new length
+    let rent_exempt_lamports = rent_exempt_lamports(new_len)?;
+
+    // Check the difference between the rent exemption and the current lamports
+    let top_up_lamports =
+        rent_exempt_lamports.saturating_sub(info.lamports().saturating_sub(reserved_lamports));
+
+    // System Transfer the remaining difference to the account
+    let transfer_context = CpiContext::new(
+        system_program.clone(),
+        system_program::Transfer {
+            from: payer.clone(),
+            to: info.clone(),
+        },
+    );
+    system_program::transfer(transfer_context, top_up_lamports)?;
+
+    info.realloc(new_len, true).map_err(Into::into)
+}
@@ -8,6 +8,18 @@
 import * as web3 from '@solana/web3.js'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import { ApprovalPolicy, approvalPolicyBeet } from '../types/ApprovalPolicy'
+import { Executor, executorBeet } from '../types/Executor'
+import { ValidityBound, validityBoundBeet } from '../types/ValidityBound'
+import { Assertion, assertionBeet } from '../types/Assertion'
+import {
+  InstructionReturnData,
+  instructionReturnDataBeet,
+} from '../types/InstructionReturnData'
+import {
+  InstructionComputeUnits,
+  instructionComputeUnitsBeet,
+} from '../types/InstructionComputeUnits'
 import {
   SuperTransactionMessage,
   superTransactionMessageBeet,
@@ -20,7 +32,25 @@ import {
  */
 export type SuperTransactionArgs = {
   creator: web3.PublicKey
+  index: number
+  bump: number
+  createdAtSlot: beet.bignum
+  createdAtUnix: beet.bignum
+  memo: beet.COption<string>
+  approval: beet.COption<ApprovalPolicy>
+  executor: Executor
+  bountyLamports: beet.bignum
+  validAfter: beet.COption<ValidityBound>
+  validUntil: beet.COption<ValidityBound>
+  assertions: Assertion[]
   ephemeralSignerBumps: Uint8Array
+  nextInstructionIndex: number
+  skippedInstructions: Uint8Array
+  captureReturnData: boolean
+  returnData: InstructionReturnData[]
+  computeUnits: InstructionComputeUnits[]
+  pendingMessageHash: beet.COption<number[] /* size: 32 */>
+  batch: beet.COption<web3.PublicKey>
   message: SuperTransactionMessage
 }
 
@@ -35,7 +65,25 @@ export const superTransactionDiscriminator = [40, 142, 137, 8, 91, 144, 93, 235]
 export class SuperTransaction implements SuperTransactionArgs {
   private constructor(
     readonly creator: web3.PublicKey,
+    readonly index: number,
+    readonly bump: number,
+    readonly createdAtSlot: beet.bignum,
+    readonly createdAtUnix: beet.bignum,
+    readonly memo: beet.COption<string>,
+    readonly approval: beet.COption<ApprovalPolicy>,
+    readonly executor: Executor,
+    readonly bountyLamports: beet.bignum,
+    readonly validAfter: beet.COption<ValidityBound>,
+    readonly validUntil: beet.COption<ValidityBound>,
+    readonly assertions: Assertion[],
     readonly ephemeralSignerBumps: Uint8Array,
+    readonly nextInstructionIndex: number,
+    readonly skippedInstructions: Uint8Array,
+    readonly captureReturnData: boolean,
+    readonly returnData: InstructionReturnData[],
+    readonly computeUnits: InstructionComputeUnits[],
+    readonly pendingMessageHash: beet.COption<number[] /* size: 32 */>,
+    readonly batch: beet.COption<web3.PublicKey>,
     readonly message: SuperTransactionMessage
   ) {}
 
@@ -45,7 +93,25 @@ export class SuperTransaction implements SuperTransactionArgs {
   static fromArgs(args: SuperTransactionArgs) {
     return new SuperTransaction(
       args.creator,
+      args.index,
+      args.bump,
+      args.createdAtSlot,
+      args.createdAtUnix,
+      args.memo,
+      args.approval,
+      args.executor,
+      args.bountyLamports,
+      args.validAfter,
+      args.validUntil,
+      args.assertions,
       args.ephemeralSignerBumps,
+      args.nextInstructionIndex,
+      args.skippedInstructions,
+      args.captureReturnData,
+      args.returnData,
+      args.computeUnits,
+      args.pendingMessageHash,
+      args.batch,
       args.message
     )
   }
@@ -156,7 +222,55 @@ export class SuperTransaction implements SuperTransactionArgs {
   pretty() {
     return {
       creator: this.creator.toBase58(),
+      index: this.index,
+      bump: this.bump,
+      createdAtSlot: (() => {
+        const x = <{ toNumber: () => number }>this.createdAtSlot
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      createdAtUnix: (() => {
+        const x = <{ toNumber: () => number }>this.createdAtUnix
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      memo: this.memo,
+      approval: this.approval,
+      executor: this.executor,
+      bountyLamports: (() => {
+        const x = <{ toNumber: () => number }>this.bountyLamports
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      validAfter: this.validAfter,
+      validUntil: this.validUntil,
+      assertions: this.assertions,
       ephemeralSignerBumps: this.ephemeralSignerBumps,
+      nextInstructionIndex: this.nextInstructionIndex,
+      skippedInstructions: this.skippedInstructions,
+      captureReturnData: this.captureReturnData,
+      returnData: this.returnData,
+      computeUnits: this.computeUnits,
+      pendingMessageHash: this.pendingMessageHash,
+      batch: this.batch,
       message: this.message,
     }
   }
@@ -175,7 +289,28 @@ export const superTransactionBeet = new beet.FixableBeetStruct<
   [
     ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
     ['creator', beetSolana.publicKey],
+    ['index', beet.u8],
+    ['bump', beet.u8],
+    ['createdAtSlot', beet.u64],
+    ['createdAtUnix', beet.i64],
+    ['memo', beet.coption(beet.utf8String)],
+    ['approval', beet.coption(approvalPolicyBeet)],
+    ['executor', executorBeet],
+    ['bountyLamports', beet.u64],
+    ['validAfter', beet.coption(validityBoundBeet)],
+    ['validUntil', beet.coption(validityBoundBeet)],
+    ['assertions', beet.array(assertionBeet)],
     ['ephemeralSignerBumps', beet.bytes],
+    ['nextInstructionIndex', beet.u8],
+    ['skippedInstructions', beet.bytes],
+    ['captureReturnData', beet.bool],
+    ['returnData', beet.array(instructionReturnDataBeet)],
+    ['computeUnits', beet.array(instructionComputeUnitsBeet)],
+    [
+      'pendingMessageHash',
+      beet.coption(beet.uniformFixedSizeArray(beet.u8, 32)),
+    ],
+    ['batch', beet.coption(beetSolana.publicKey)],
     ['message', superTransactionMessageBeet],
   ],
   SuperTransaction.fromArgs,
@@ -19,7 +19,9 @@ export type TransactionBufferArgs = {
   bufferIndex: number
   finalBufferHash: number[] /* size: 32 */
   finalBufferSize: number
+  chunkMerkleRoot: beet.COption<number[] /* size: 32 */>
   buffer: Uint8Array
+  writtenChunks: Uint8Array
 }
 
 export const transactionBufferDiscriminator = [
@@ -38,7 +40,9 @@ export class TransactionBuffer implements TransactionBufferArgs {
     readonly bufferIndex: number,
     readonly finalBufferHash: number[] /* size: 32 */,
     readonly finalBufferSize: number,
-    readonly buffer: Uint8Array
+    readonly chunkMerkleRoot: beet.COption<number[] /* size: 32 */>,
+    readonly buffer: Uint8Array,
+    readonly writtenChunks: Uint8Array
   ) {}
 
   /**
@@ -50,7 +54,9 @@ export class TransactionBuffer implements TransactionBufferArgs {
       args.bufferIndex,
       args.finalBufferHash,
       args.finalBufferSize,
-      args.buffer
+      args.chunkMerkleRoot,
+      args.buffer,
+      args.writtenChunks
     )
   }
 
@@ -163,7 +169,9 @@ export class TransactionBuffer implements TransactionBufferArgs {
       bufferIndex: this.bufferIndex,
       finalBufferHash: this.finalBufferHash,
       finalBufferSize: this.finalBufferSize,
+      chunkMerkleRoot: this.chunkMerkleRoot,
       buffer: this.buffer,
+      writtenChunks: this.writtenChunks,
     }
   }
 }
@@ -183,8 +191,13 @@ export const transactionBufferBeet = new beet.FixableBeetStruct<
     ['creator', beetSolana.publicKey],
     ['bufferIndex', beet.u8],
     ['finalBufferHash', beet.uniformFixedSizeArray(beet.u8, 32)],
-    ['finalBufferSize', beet.u16],
+    ['finalBufferSize', beet.u32],
+    [
+      'chunkMerkleRoot',
+      beet.coption(beet.uniformFixedSizeArray(beet.u8, 32)),
+    ],
     ['buffer', beet.bytes],
+    ['writtenChunks', beet.bytes],
   ],
   TransactionBuffer.fromArgs,
   'TransactionBuffer'
@@ -1,7 +1,16 @@
+export * from './Batch'
+export * from './ExecutionPolicy'
 export * from './SuperTransaction'
 export * from './TransactionBuffer'
 
+import { Batch } from './Batch'
+import { ExecutionPolicy } from './ExecutionPolicy'
 import { TransactionBuffer } from './TransactionBuffer'
 import { SuperTransaction } from './SuperTransaction'
 
-export const accountProviders = { TransactionBuffer, SuperTransaction }
+export const accountProviders = {
+  Batch,
+  ExecutionPolicy,
+  TransactionBuffer,
+  SuperTransaction,
+}
@@ -968,7 +968,7 @@ createErrorFromNameLookup.set(
 )
 
 /**
- * FinalBufferSizeExceeded: 'Final buffer size cannot exceed 4000 bytes'
+ * FinalBufferSizeExceeded: 'Final buffer size exceeds the maximum allowed'
  *
  * @category Errors
  * @category generated
@@ -977,7 +977,7 @@ export class FinalBufferSizeExceededError extends Error {
   readonly code: number = 0x179a
   readonly name: string = 'FinalBufferSizeExceeded'
   constructor() {
-    super('Final buffer size cannot exceed 4000 bytes')
+    super('Final buffer size exceeds the maximum allowed')
     if (typeof Error.captureStackTrace === 'function') {
       Error.captureStackTrace(this, FinalBufferSizeExceededError)
     }
@@ -1038,6 +1038,420 @@ createErrorFromNameLookup.set(
   () => new MultisigCreateDeprecatedError()
 )
 
+/**
+ * UnalignedBufferWrite: 'Buffer writes must start on a chunk boundary and cover whole chunks'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnalignedBufferWriteError extends Error {
+  readonly code: number = 0x179d
+  readonly name: string = 'UnalignedBufferWrite'
+  constructor() {
+    super('Buffer writes must start on a chunk boundary and cover whole chunks')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnalignedBufferWriteError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new UnalignedBufferWriteError())
+createErrorFromNameLookup.set(
+  'UnalignedBufferWrite',
+  () => new UnalignedBufferWriteError()
+)
+
+/**
+ * BufferChunksIncomplete: 'Not every chunk of the transaction buffer has been written'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BufferChunksIncompleteError extends Error {
+  readonly code: number = 0x179e
+  readonly name: string = 'BufferChunksIncomplete'
+  constructor() {
+    super('Not every chunk of the transaction buffer has been written')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BufferChunksIncompleteError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new BufferChunksIncompleteError())
+createErrorFromNameLookup.set(
+  'BufferChunksIncomplete',
+  () => new BufferChunksIncompleteError()
+)
+
+/**
+ * ChunkProofMismatch: 'Buffer chunk doesn't match the committed chunk Merkle root'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChunkProofMismatchError extends Error {
+  readonly code: number = 0x179f
+  readonly name: string = 'ChunkProofMismatch'
+  constructor() {
+    super("Buffer chunk doesn't match the committed chunk Merkle root")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChunkProofMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new ChunkProofMismatchError())
+createErrorFromNameLookup.set(
+  'ChunkProofMismatch',
+  () => new ChunkProofMismatchError()
+)
+
+/**
+ * AccountNotLargeEnough: 'Account is too small, grow it with a realloc instruction first'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountNotLargeEnoughError extends Error {
+  readonly code: number = 0x17a0
+  readonly name: string = 'AccountNotLargeEnough'
+  constructor() {
+    super('Account is too small, grow it with a realloc instruction first')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccountNotLargeEnoughError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new AccountNotLargeEnoughError())
+createErrorFromNameLookup.set(
+  'AccountNotLargeEnough',
+  () => new AccountNotLargeEnoughError()
+)
+
+/**
+ * TransactionTooLarge: 'SuperTransaction exceeds the maximum account size'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionTooLargeError extends Error {
+  readonly code: number = 0x17a1
+  readonly name: string = 'TransactionTooLarge'
+  constructor() {
+    super('SuperTransaction exceeds the maximum account size')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionTooLargeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new TransactionTooLargeError())
+createErrorFromNameLookup.set(
+  'TransactionTooLarge',
+  () => new TransactionTooLargeError()
+)
+
+/**
+ * TransactionNotPending: 'SuperTransaction message has already been written'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionNotPendingError extends Error {
+  readonly code: number = 0x17a2
+  readonly name: string = 'TransactionNotPending'
+  constructor() {
+    super('SuperTransaction message has already been written')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionNotPendingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new TransactionNotPendingError())
+createErrorFromNameLookup.set(
+  'TransactionNotPending',
+  () => new TransactionNotPendingError()
+)
+
+/**
+ * MemoTooLong: 'Memo exceeds the maximum allowed length'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MemoTooLongError extends Error {
+  readonly code: number = 0x17a3
+  readonly name: string = 'MemoTooLong'
+  constructor() {
+    super('Memo exceeds the maximum allowed length')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MemoTooLongError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new MemoTooLongError())
+createErrorFromNameLookup.set('MemoTooLong', () => new MemoTooLongError())
+
+/**
+ * BatchFull: 'Batch cannot hold any more transactions'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BatchFullError extends Error {
+  readonly code: number = 0x17a4
+  readonly name: string = 'BatchFull'
+  constructor() {
+    super('Batch cannot hold any more transactions')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BatchFullError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new BatchFullError())
+createErrorFromNameLookup.set('BatchFull', () => new BatchFullError())
+
+/**
+ * ApprovalPolicyMissing: 'Transaction has no approval policy'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ApprovalPolicyMissingError extends Error {
+  readonly code: number = 0x17a5
+  readonly name: string = 'ApprovalPolicyMissing'
+  constructor() {
+    super('Transaction has no approval policy')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ApprovalPolicyMissingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new ApprovalPolicyMissingError())
+createErrorFromNameLookup.set(
+  'ApprovalPolicyMissing',
+  () => new ApprovalPolicyMissingError()
+)
+
+/**
+ * BountyNotCovered: 'SuperTransaction lamports don't cover both the bounty and the rent exemption'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BountyNotCoveredError extends Error {
+  readonly code: number = 0x17a6
+  readonly name: string = 'BountyNotCovered'
+  constructor() {
+    super(
+      "SuperTransaction lamports don't cover both the bounty and the rent exemption"
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BountyNotCoveredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new BountyNotCoveredError())
+createErrorFromNameLookup.set(
+  'BountyNotCovered',
+  () => new BountyNotCoveredError()
+)
+
+/**
+ * TransactionNotYetValid: 'SuperTransaction is not valid yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionNotYetValidError extends Error {
+  readonly code: number = 0x17a7
+  readonly name: string = 'TransactionNotYetValid'
+  constructor() {
+    super('SuperTransaction is not valid yet')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionNotYetValidError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new TransactionNotYetValidError())
+createErrorFromNameLookup.set(
+  'TransactionNotYetValid',
+  () => new TransactionNotYetValidError()
+)
+
+/**
+ * TransactionExpired: 'SuperTransaction has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionExpiredError extends Error {
+  readonly code: number = 0x17a8
+  readonly name: string = 'TransactionExpired'
+  constructor() {
+    super('SuperTransaction has expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new TransactionExpiredError())
+createErrorFromNameLookup.set(
+  'TransactionExpired',
+  () => new TransactionExpiredError()
+)
+
+/**
+ * TransactionNotExpired: 'SuperTransaction has not expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionNotExpiredError extends Error {
+  readonly code: number = 0x17a9
+  readonly name: string = 'TransactionNotExpired'
+  constructor() {
+    super('SuperTransaction has not expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionNotExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new TransactionNotExpiredError())
+createErrorFromNameLookup.set(
+  'TransactionNotExpired',
+  () => new TransactionNotExpiredError()
+)
+
+/**
+ * AssertionFailed: 'Post-execution assertion failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssertionFailedError extends Error {
+  readonly code: number = 0x17aa
+  readonly name: string = 'AssertionFailed'
+  constructor() {
+    super('Post-execution assertion failed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssertionFailedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new AssertionFailedError())
+createErrorFromNameLookup.set(
+  'AssertionFailed',
+  () => new AssertionFailedError()
+)
+
+/**
+ * ProgramNotAllowed: 'Instruction calls a program not allowed by the execution policy'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProgramNotAllowedError extends Error {
+  readonly code: number = 0x17ab
+  readonly name: string = 'ProgramNotAllowed'
+  constructor() {
+    super('Instruction calls a program not allowed by the execution policy')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProgramNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new ProgramNotAllowedError())
+createErrorFromNameLookup.set(
+  'ProgramNotAllowed',
+  () => new ProgramNotAllowedError()
+)
+
+/**
+ * TransactionPending: 'SuperTransaction is pending, complete it with super_transaction_realloc'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionPendingError extends Error {
+  readonly code: number = 0x17ac
+  readonly name: string = 'TransactionPending'
+  constructor() {
+    super(
+      'SuperTransaction is pending, complete it with super_transaction_realloc'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionPendingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new TransactionPendingError())
+createErrorFromNameLookup.set(
+  'TransactionPending',
+  () => new TransactionPendingError()
+)
+
+/**
+ * TransactionInBatch: 'SuperTransaction belongs to a batch, execute or close it through the batch'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionInBatchError extends Error {
+  readonly code: number = 0x17ad
+  readonly name: string = 'TransactionInBatch'
+  constructor() {
+    super(
+      'SuperTransaction belongs to a batch, execute or close it through the batch'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionInBatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new TransactionInBatchError())
+createErrorFromNameLookup.set(
+  'TransactionInBatch',
+  () => new TransactionInBatchError()
+)
+
+/**
+ * InvalidBatchTransactions: 'Batch transactions passed out of order or not all of them'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBatchTransactionsError extends Error {
+  readonly code: number = 0x17ae
+  readonly name: string = 'InvalidBatchTransactions'
+  constructor() {
+    super('Batch transactions passed out of order or not all of them')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBatchTransactionsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new InvalidBatchTransactionsError())
+createErrorFromNameLookup.set(
+  'InvalidBatchTransactions',
+  () => new InvalidBatchTransactionsError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
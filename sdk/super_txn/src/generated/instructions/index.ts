@@ -1,7 +1,24 @@
+export * from './batchAddTransaction'
+export * from './batchClose'
+export * from './batchCreate'
+export * from './batchExecuteNext'
+export * from './executionPolicyClose'
+export * from './executionPolicyCreate'
+export * from './executionPolicyUpdate'
 export * from './superTransactionAccountsClose'
+export * from './superTransactionApprove'
+export * from './superTransactionCancel'
+export * from './superTransactionCloseExpired'
 export * from './superTransactionCreate'
 export * from './superTransactionCreateFromBuffer'
 export * from './superTransactionExecute'
+export * from './superTransactionExecuteBestEffort'
+export * from './superTransactionExecuteRange'
+export * from './superTransactionRealloc'
+export * from './superTransactionReject'
 export * from './txnBufferClose'
 export * from './txnBufferCreate'
+export * from './txnBufferExecute'
 export * from './txnBufferExtend'
+export * from './txnBufferRealloc'
+export * from './txnBufferWriteAt'
@@ -7,23 +7,42 @@
 
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import {
+  SuperTransactionExecuteArgs,
+  superTransactionExecuteArgsBeet,
+} from '../types/SuperTransactionExecuteArgs'
 
 /**
  * @category Instructions
  * @category SuperTransactionExecute
  * @category generated
  */
-export const superTransactionExecuteStruct = new beet.BeetArgsStruct<{
-  instructionDiscriminator: number[] /* size: 8 */
-}>(
-  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+export type SuperTransactionExecuteInstructionArgs = {
+  args: SuperTransactionExecuteArgs
+}
+/**
+ * @category Instructions
+ * @category SuperTransactionExecute
+ * @category generated
+ */
+export const superTransactionExecuteStruct = new beet.BeetArgsStruct<
+  SuperTransactionExecuteInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', superTransactionExecuteArgsBeet],
+  ],
   'SuperTransactionExecuteInstructionArgs'
 )
 /**
  * Accounts required by the _superTransactionExecute_ instruction
  *
  * @property [_writable_] transaction
- * @property [_writable_, **signer**] creator
+ * @property [_writable_] creator
+ * @property [_writable_, **signer**] executor
+ * @property [] executionPolicy
  * @category Instructions
  * @category SuperTransactionExecute
  * @category generated
@@ -31,6 +50,9 @@ export const superTransactionExecuteStruct = new beet.BeetArgsStruct<{
 export type SuperTransactionExecuteInstructionAccounts = {
   transaction: web3.PublicKey
   creator: web3.PublicKey
+  executor: web3.PublicKey
+  executionPolicy: web3.PublicKey
+  systemProgram?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -42,16 +64,20 @@ export const superTransactionExecuteInstructionDiscriminator = [
  * Creates a _SuperTransactionExecute_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
  * @category Instructions
  * @category SuperTransactionExecute
  * @category generated
  */
 export function createSuperTransactionExecuteInstruction(
   accounts: SuperTransactionExecuteInstructionAccounts,
+  args: SuperTransactionExecuteInstructionArgs,
   programId = new web3.PublicKey('superB6bzm82y1To5rRaMr7KmqkLNVnCUGwUBemtJV3')
 ) {
   const [data] = superTransactionExecuteStruct.serialize({
     instructionDiscriminator: superTransactionExecuteInstructionDiscriminator,
+    ...args,
   })
   const keys: web3.AccountMeta[] = [
     {
@@ -62,8 +88,23 @@ export function createSuperTransactionExecuteInstruction(
     {
       pubkey: accounts.creator,
       isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.executor,
+      isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.executionPolicy,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -23,7 +23,7 @@ export const txnBufferCloseStruct = new beet.BeetArgsStruct<{
  * Accounts required by the _txnBufferClose_ instruction
  *
  * @property [_writable_] transactionBuffer
- * @property [**signer**] creator
+ * @property [_writable_, **signer**] creator
  * @category Instructions
  * @category TxnBufferClose
  * @category generated
@@ -61,7 +61,7 @@ export function createTxnBufferCloseInstruction(
     },
     {
       pubkey: accounts.creator,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
   ]
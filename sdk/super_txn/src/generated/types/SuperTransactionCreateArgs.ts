@@ -6,11 +6,26 @@
  */
 
 import * as beet from '@metaplex-foundation/beet'
+import {
+  ApprovalPolicyArgs,
+  approvalPolicyArgsBeet,
+} from './ApprovalPolicyArgs'
+import { Executor, executorBeet } from './Executor'
+import { ValidityBound, validityBoundBeet } from './ValidityBound'
+import { Assertion, assertionBeet } from './Assertion'
 export type SuperTransactionCreateArgs = {
   transactionIndex: number
   ephemeralSigners: number
   transactionMessage: Uint8Array
   memo: beet.COption<string>
+  approval: beet.COption<ApprovalPolicyArgs>
+  executor: Executor
+  bountyLamports: beet.bignum
+  validAfter: beet.COption<ValidityBound>
+  validUntil: beet.COption<ValidityBound>
+  assertions: Assertion[]
+  captureReturnData: boolean
+  strict: boolean
 }
 
 /**
@@ -24,6 +39,14 @@ export const superTransactionCreateArgsBeet =
       ['ephemeralSigners', beet.u8],
       ['transactionMessage', beet.bytes],
       ['memo', beet.coption(beet.utf8String)],
+      ['approval', beet.coption(approvalPolicyArgsBeet)],
+      ['executor', executorBeet],
+      ['bountyLamports', beet.u64],
+      ['validAfter', beet.coption(validityBoundBeet)],
+      ['validUntil', beet.coption(validityBoundBeet)],
+      ['assertions', beet.array(assertionBeet)],
+      ['captureReturnData', beet.bool],
+      ['strict', beet.bool],
     ],
     'SuperTransactionCreateArgs'
   )
@@ -11,6 +11,7 @@ export type TransactionBufferCreateArgs = {
   finalBufferHash: number[] /* size: 32 */
   finalBufferSize: number
   buffer: Uint8Array
+  chunkMerkleRoot: beet.COption<number[] /* size: 32 */>
 }
 
 /**
@@ -22,8 +23,12 @@ export const transactionBufferCreateArgsBeet =
     [
       ['bufferIndex', beet.u8],
       ['finalBufferHash', beet.uniformFixedSizeArray(beet.u8, 32)],
-      ['finalBufferSize', beet.u16],
+      ['finalBufferSize', beet.u32],
       ['buffer', beet.bytes],
+      [
+        'chunkMerkleRoot',
+        beet.coption(beet.uniformFixedSizeArray(beet.u8, 32)),
+      ],
     ],
     'TransactionBufferCreateArgs'
   )
@@ -8,6 +8,8 @@
 import * as beet from '@metaplex-foundation/beet'
 export type TransactionBufferExtendArgs = {
   buffer: Uint8Array
+  chunkProof: beet.COption<number[] /* size: 32 */[]>
+  strict: boolean
 }
 
 /**
@@ -16,6 +18,13 @@ export type TransactionBufferExtendArgs = {
  */
 export const transactionBufferExtendArgsBeet =
   new beet.FixableBeetArgsStruct<TransactionBufferExtendArgs>(
-    [['buffer', beet.bytes]],
+    [
+      ['buffer', beet.bytes],
+      [
+        'chunkProof',
+        beet.coption(beet.array(beet.uniformFixedSizeArray(beet.u8, 32))),
+      ],
+      ['strict', beet.bool],
+    ],
     'TransactionBufferExtendArgs'
   )
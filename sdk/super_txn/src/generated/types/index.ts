@@ -1,6 +1,23 @@
+export * from './ApprovalPolicy'
+export * from './ApprovalPolicyArgs'
+export * from './ApprovalStatus'
+export * from './Assertion'
+export * from './BatchAddTransactionArgs'
+export * from './BatchCreateArgs'
+export * from './ExecutionPolicyArgs'
+export * from './Executor'
+export * from './InstructionComputeUnits'
+export * from './InstructionReturnData'
 export * from './SuperCompiledInstruction'
 export * from './SuperMessageAddressTableLookup'
 export * from './SuperTransactionCreateArgs'
+export * from './SuperTransactionExecuteArgs'
+export * from './SuperTransactionExecuteBestEffortArgs'
+export * from './SuperTransactionExecuteRangeArgs'
 export * from './SuperTransactionMessage'
 export * from './TransactionBufferCreateArgs'
+export * from './TransactionBufferExecuteArgs'
 export * from './TransactionBufferExtendArgs'
+export * from './TransactionBufferWriteAtArgs'
+export * from './ValidityBound'
+export * from './Vote'
@@ -1,6 +1,10 @@
 import {
+  ApprovalPolicyArgs,
+  Assertion,
   createSuperTransactionCreateInstruction,
+  Executor,
   PROGRAM_ID,
+  ValidityBound,
 } from "../generated";
 import {
   AddressLookupTableAccount,
@@ -18,6 +22,14 @@ export function superTransactionCreate({
   transactionMessage,
   addressLookupTableAccounts,
   memo,
+  approval,
+  executor,
+  bountyLamports,
+  validAfter,
+  validUntil,
+  assertions,
+  captureReturnData,
+  strict,
   programId = PROGRAM_ID,
 }: {
   creator: PublicKey;
@@ -30,6 +42,19 @@ export function superTransactionCreate({
   /** `AddressLookupTableAccount`s referenced in `transaction_message`. */
   addressLookupTableAccounts?: AddressLookupTableAccount[];
   memo?: string;
+  /** Members that have to approve the transaction before it can be executed. */
+  approval?: ApprovalPolicyArgs;
+  /** Who can execute the transaction, only the creator by default. */
+  executor?: Executor;
+  /** Lamports paid to the executor once every instruction has been executed. */
+  bountyLamports?: number;
+  validAfter?: ValidityBound;
+  validUntil?: ValidityBound;
+  /** Checked once every instruction has been executed. */
+  assertions?: Assertion[];
+  /** Keep the return data of the executed instructions on the transaction account. */
+  captureReturnData?: boolean;
+  strict?: boolean;
   programId?: PublicKey;
 }) {
 
@@ -57,6 +82,14 @@ export function superTransactionCreate({
         ephemeralSigners,
         transactionMessage: transactionMessageBytes,
         memo: memo ?? null,
+        approval: approval ?? null,
+        executor: executor ?? { __kind: "Creator" },
+        bountyLamports: bountyLamports ?? 0,
+        validAfter: validAfter ?? null,
+        validUntil: validUntil ?? null,
+        assertions: assertions ?? [],
+        captureReturnData: captureReturnData ?? false,
+        strict: strict ?? false,
       },
     },
     programId
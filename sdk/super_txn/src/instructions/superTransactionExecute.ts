@@ -7,7 +7,7 @@ import {
   TransactionInstruction,
   TransactionMessage,
 } from "@solana/web3.js";
-import { getTransactionPda } from "../pda";
+import { getExecutionPolicyPda, getTransactionPda } from "../pda";
 import {
   createSuperTransactionExecuteInstruction,
   PROGRAM_ID,
@@ -22,12 +22,18 @@ import {
 export async function superTransactionExecute({
   connection,
   creator,
+  executor,
   transactionIndex,
+  strict = false,
   programId = PROGRAM_ID,
 }: {
   connection: Connection;
   creator: PublicKey;
+  /** Signer executing the transaction, the creator by default. */
+  executor?: PublicKey;
   transactionIndex: number;
+  /** Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist. */
+  strict?: boolean;
   programId?: PublicKey;
 }): Promise<{
   instruction: TransactionInstruction;
@@ -71,8 +77,11 @@ export async function superTransactionExecute({
       {
         creator,
         transaction: transactionPda,
+        executor: executor ?? creator,
+        executionPolicy: getExecutionPolicyPda({ creator, programId })[0],
         anchorRemainingAccounts: accountMetas,
       },
+      { args: { strict } },
       programId
     ),
     lookupTableAccounts,
@@ -82,16 +91,22 @@ export async function superTransactionExecute({
 export async function bundledSuperTransactionExecute({
   connection,
   creator,
+  executor,
   transactionMessage,
   transactionIndex,
   addressLookupTableAccounts,
+  strict = false,
   programId = PROGRAM_ID,
 }: {
   connection: Connection;
   creator: PublicKey;
+  /** Signer executing the transaction, the creator by default. */
+  executor?: PublicKey;
   transactionMessage: TransactionMessage;
   addressLookupTableAccounts: AddressLookupTableAccount[];
   transactionIndex: number;
+  /** Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist. */
+  strict?: boolean;
   programId?: PublicKey;
 }): Promise<{
   instruction: TransactionInstruction;
@@ -130,8 +145,11 @@ export async function bundledSuperTransactionExecute({
       {
         creator,
         transaction: transactionPda,
+        executor: executor ?? creator,
+        executionPolicy: getExecutionPolicyPda({ creator, programId })[0],
         anchorRemainingAccounts: accountMetas,
       },
+      { args: { strict } },
       programId
     ),
     lookupTableAccounts,
@@ -6,6 +6,7 @@ const SEED_PREFIX = toUtfBytes("super_txn");
 const SEED_TRANSACTION = toUtfBytes("transaction");
 const SEED_EPHEMERAL_SIGNER = toUtfBytes("ephemeral_signer");
 const SEED_TRANSACTION_BUFFER = toUtfBytes("transaction_buffer");
+const SEED_EXECUTION_POLICY = toUtfBytes("execution_policy");
 
 export function getEphemeralSignerPda({
   transactionPda,
@@ -61,4 +62,17 @@ export function getTransactionPda({
     [SEED_PREFIX, SEED_TRANSACTION, creator.toBytes(), toU8Bytes(index)],
     programId
   );
-}
\ No newline at end of file
+}
+
+export function getExecutionPolicyPda({
+  creator,
+  programId = PROGRAM_ID,
+}: {
+  creator: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, SEED_EXECUTION_POLICY, creator.toBytes()],
+    programId
+  );
+}
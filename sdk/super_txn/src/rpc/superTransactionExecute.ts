@@ -18,6 +18,7 @@ export async function superTransactionExecute({
   creator,
   transactionIndex,
   member,
+  strict,
   signers,
   sendOptions,
   programId,
@@ -27,6 +28,8 @@ export async function superTransactionExecute({
   creator: PublicKey;
   transactionIndex: number;
   member: PublicKey;
+  /** Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist. */
+  strict?: boolean;
   signers?: Signer[];
   sendOptions?: SendOptions;
   programId?: PublicKey;
@@ -38,7 +41,9 @@ export async function superTransactionExecute({
     blockhash,
     feePayer: feePayer.publicKey,
     creator,
+    executor: member,
     transactionIndex,
+    strict,
     programId,
   });
 
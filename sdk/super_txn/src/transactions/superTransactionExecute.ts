@@ -15,21 +15,29 @@ export async function superTransactionExecute({
   blockhash,
   feePayer,
   creator,
+  executor,
   transactionIndex,
+  strict,
   programId,
 }: {
   connection: Connection;
   blockhash: string;
   feePayer: PublicKey;
   creator: PublicKey;
+  /** Signer executing the transaction, the creator by default. */
+  executor?: PublicKey;
   transactionIndex: number;
+  /** Fail with `MissingAccount` instead of silently succeeding if the transaction doesn't exist. */
+  strict?: boolean;
   programId?: PublicKey;
 }): Promise<VersionedTransaction> {
   const { instruction, lookupTableAccounts } =
     await instructions.superTransactionExecute({
       connection,
       creator,
+      executor,
       transactionIndex,
+      strict,
       programId,
     });
 